@@ -0,0 +1,72 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use hollow_knight_save_parser::{HollowKnightSave, Parser, decrypt_to_json};
+use serde_json::json;
+
+/// Scene name prefixes, so that the padding looks like the scenes checks
+/// actually ask for.
+const AREAS: &[&str] = &[
+    "Crossroads_",
+    "Fungus1_",
+    "Fungus2_",
+    "Fungus3_",
+    "Ruins1_",
+    "Ruins2_",
+    "Mines_",
+    "RestingGrounds_",
+    "Waterways_",
+    "Deepnest_",
+    "Deepnest_East_",
+    "Abyss_",
+    "Hive_",
+    "Cliffs_",
+    "White_Palace_",
+];
+
+/// Objects the game saves per scene: enemies, levers, breakable walls...
+const OBJECTS: usize = 12;
+
+/// The test save padded out with `items` more `persistentBoolItems`, a
+/// late-game save has a few thousand. The padding goes first, like the
+/// scenes of a long run that come before the last few pickups.
+fn save(items: usize) -> HollowKnightSave {
+    let data = std::fs::read("tests/saves/hollow_knight.dat").expect("test save");
+    let mut json = decrypt_to_json(&data).expect("valid save");
+    let list = json["sceneData"]["persistentBoolItems"]
+        .as_array_mut()
+        .expect("persistentBoolItems");
+    let padding = (0..items).map(|i| {
+        let scene = i / OBJECTS;
+        let area = AREAS[scene % AREAS.len()];
+        json!({
+            "id": format!("Breakable Wall ({})", i % OBJECTS),
+            "sceneName": format!("{area}{:02}b", scene / AREAS.len()),
+            "activated": i % 2 == 0,
+            "semiPersistent": false,
+        })
+    });
+    list.splice(0..0, padding);
+    serde_json::from_value(json).expect("valid save")
+}
+
+fn checks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hollow knight checks");
+    for items in [0, 1_000, 4_000, 16_000] {
+        let save = save(items);
+        group.bench_with_input(BenchmarkId::from_parameter(items), &save, |b, save| {
+            b.iter(|| black_box(save).checks());
+        });
+    }
+    group.finish();
+}
+
+/// Everything the web app gets from an upload: checks, completion and
+/// statistics.
+fn parse(c: &mut Criterion) {
+    let data = std::fs::read("tests/saves/hollow_knight.dat").expect("test save");
+    c.bench_function("hollow knight parse", |b| {
+        b.iter(|| Parser::new().parse_save_file(black_box(&data)));
+    });
+}
+
+criterion_group!(benches, checks, parse);
+criterion_main!(benches);
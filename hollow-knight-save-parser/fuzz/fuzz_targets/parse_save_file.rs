@@ -0,0 +1,12 @@
+#![no_main]
+
+use hollow_knight_save_parser::{Parser, encode_save_file};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Arbitrary bytes exercise the header, length and base64 checks.
+    let _ = Parser::new().parse_save_file(data);
+    // Wrapping them as a valid save gets past those into game detection and
+    // JSON parsing.
+    let _ = Parser::new().parse_save_file(&encode_save_file(data));
+});
@@ -0,0 +1,346 @@
+use indexmap::IndexMap;
+use serde::{Serialize, Serializer, ser::SerializeStruct};
+use serde_json::Value;
+
+use crate::Number;
+
+/// How a check is decided from the save.
+#[derive(Debug, Clone, Copy)]
+pub enum Predicate {
+    /// `playerData.<path>` is `true`. Nested fields are separated by dots.
+    Flag(&'static str),
+    /// `playerData.<path>` is at least the given number.
+    AtLeast(&'static str, Number),
+    /// `playerData.<path>` is less than the given number.
+    Below(&'static str, Number),
+    /// `playerData.<path>` is exactly the given number, for counters that
+    /// go on past the value that matters.
+    Equals(&'static str, Number),
+    /// At least `n` pieces were collected, counting the ones already turned
+    /// into whole items, e.g. mask shards into masks. Works whether the game
+    /// restarts the `pieces` counter at every whole item or keeps counting:
+    /// `max(pieces, (whole - base) * per + pieces % per)`.
+    Pieces {
+        pieces: &'static str,
+        whole: &'static str,
+        /// Value of `whole` before any piece was collected.
+        base: Number,
+        /// Pieces per whole item.
+        per: Number,
+        n: Number,
+    },
+    /// `(sceneName, id)` is activated in `sceneData.persistentBoolItems`.
+    Scene(&'static str, &'static str),
+    /// `playerData.<list>` has an unlocked entry with the given name. Used
+    /// for Unity-serialized `{ "savedData": [{ "Name", "Data" }] }` lists.
+    Unlocked(&'static str, &'static str),
+    /// Every predicate holds.
+    All(&'static [Predicate]),
+    /// At least one predicate holds.
+    Any(&'static [Predicate]),
+    /// Follows from more of the save than a predicate can look at, like
+    /// every journal entry being discovered. Never holds on its own, the
+    /// game's `checks` sets these after evaluating the rest.
+    Derived,
+}
+
+impl Predicate {
+    pub(crate) fn eval(
+        &self,
+        player_data: &Value,
+        scene_activated: &impl Fn(&str, &str) -> bool,
+    ) -> bool {
+        let field = |path: &str| path.split('.').try_fold(player_data, |v, key| v.get(key));
+        match *self {
+            Self::Flag(path) => field(path).and_then(Value::as_bool).unwrap_or(false),
+            Self::AtLeast(path, n) => field(path).and_then(Value::as_f64).is_some_and(|v| v >= n),
+            Self::Below(path, n) => field(path).and_then(Value::as_f64).is_some_and(|v| v < n),
+            Self::Equals(path, n) => field(path).and_then(Value::as_f64).is_some_and(|v| v == n),
+            Self::Pieces {
+                pieces,
+                whole,
+                base,
+                per,
+                n,
+            } => {
+                let number = |path: &str| field(path).and_then(Value::as_f64);
+                let pieces = number(pieces).unwrap_or(0.0);
+                let whole = number(whole).unwrap_or(base).max(base);
+                pieces.max((whole - base) * per + pieces % per) >= n
+            }
+            Self::Scene(name, id) => scene_activated(name, id),
+            Self::Unlocked(list, name) => field(list)
+                .and_then(|list| list.get("savedData"))
+                .and_then(Value::as_array)
+                .and_then(|entries| entries.iter().find(|e| e["Name"] == name))
+                .and_then(|entry| entry["Data"]["IsUnlocked"].as_bool())
+                .unwrap_or(false),
+            Self::All(predicates) => predicates
+                .iter()
+                .all(|p| p.eval(player_data, scene_activated)),
+            Self::Any(predicates) => predicates
+                .iter()
+                .any(|p| p.eval(player_data, scene_activated)),
+            Self::Derived => false,
+        }
+    }
+}
+
+/// A single checklist entry.
+#[derive(Debug, Clone, Copy)]
+pub struct CheckDef {
+    /// Stable `category.snake_case` identifier, never changes once released.
+    pub id: &'static str,
+    /// Display label with wiki links: `[Page]` or `[Text](Page#Section)`.
+    /// Bare `[ALL_CAPS]` names are icons.
+    pub label: &'static str,
+    pub predicate: Predicate,
+    /// Whether the check counts towards the category's completion.
+    pub percent: bool,
+    /// How the game's own completion count decides the check, when that
+    /// differs from `predicate`. The game only looks at `playerData`.
+    pub counted_as: Option<Predicate>,
+}
+
+impl CheckDef {
+    #[must_use]
+    pub const fn new(id: &'static str, label: &'static str, predicate: Predicate) -> Self {
+        Self {
+            id,
+            label,
+            predicate,
+            percent: true,
+            counted_as: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn no_percent(self) -> Self {
+        Self {
+            percent: false,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub const fn counted_as(self, predicate: Predicate) -> Self {
+        Self {
+            counted_as: Some(predicate),
+            ..self
+        }
+    }
+}
+
+/// A checklist section, like bosses or charms.
+#[derive(Debug, Clone, Copy)]
+pub struct CategoryDef {
+    /// Name of the category, as serialized.
+    pub name: &'static str,
+    /// Completion percent one group of checks is worth.
+    pub percent: Number,
+    /// How many checks make up one group, e.g. four mask shards per mask.
+    pub group: usize,
+    /// Empty for categories whose checks come from the save itself, like
+    /// Silksong's wishes.
+    pub checks: &'static [CheckDef],
+    /// Whether the category only reports on the rest of the save, like geo
+    /// rocks, rather than listing things to collect. The web app's planner
+    /// doesn't have these.
+    pub derived: bool,
+}
+
+impl CategoryDef {
+    #[must_use]
+    pub const fn new(name: &'static str, checks: &'static [CheckDef]) -> Self {
+        Self {
+            name,
+            percent: 0.0,
+            group: 1,
+            checks,
+            derived: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn derived(self) -> Self {
+        Self {
+            derived: true,
+            ..self
+        }
+    }
+
+    /// Every `group` checks are worth `percent` completion.
+    #[must_use]
+    pub const fn percent(self, percent: Number, group: usize) -> Self {
+        Self {
+            percent,
+            group,
+            ..self
+        }
+    }
+}
+
+/// An evaluated check, keyed by its id in a [`Checklist`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CheckState {
+    /// Plain text label, without the wiki markup.
+    pub label: String,
+    /// Wiki pages the label links to, like `Nail#Upgrades`.
+    pub wiki: Vec<String>,
+    pub checked: bool,
+}
+
+impl CheckState {
+    /// Splits a [`CheckDef::label`] into plain text and the pages it links
+    /// to, the same way the web app renders it.
+    pub(crate) fn new(label: &str, checked: bool) -> Self {
+        let mut text = String::new();
+        let mut wiki = Vec::new();
+        let mut rest = label;
+        while let Some(start) = rest.find('[') {
+            let Some(end) = rest[start..].find(']').map(|end| start + end) else {
+                break;
+            };
+            text.push_str(&rest[..start]);
+            let name = &rest[start + 1..end];
+            rest = &rest[end + 1..];
+
+            let page = rest.strip_prefix('(').and_then(|link| {
+                // Pages can have parens in them, e.g. `Grimm Troupe (Quest)`
+                let mut depth = 0;
+                let end = link.char_indices().find_map(|(i, c)| match c {
+                    '(' => {
+                        depth += 1;
+                        None
+                    }
+                    ')' if depth == 0 => Some(i),
+                    ')' => {
+                        depth -= 1;
+                        None
+                    }
+                    _ => None,
+                })?;
+                rest = &link[end + 1..];
+                Some(&link[..end])
+            });
+            let is_icon = name
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c == '_' || c == '\'');
+            if page.is_none() && is_icon {
+                continue;
+            }
+            text.push_str(name);
+            wiki.push(page.unwrap_or(name).to_owned());
+        }
+        text.push_str(rest);
+
+        Self {
+            label: text.trim().to_owned(),
+            wiki,
+            checked,
+        }
+    }
+}
+
+/// An evaluated checklist: for every category, the state of each check by
+/// its id. Categories and checks keep the order they are declared in.
+#[derive(Debug, Default, Clone)]
+pub struct Checklist(Vec<(&'static str, IndexMap<String, CheckState>)>);
+
+impl Checklist {
+    /// Evaluates every check in `categories` against the save's player data
+    /// and scene state.
+    pub(crate) fn evaluate(
+        categories: &[CategoryDef],
+        player_data: &Value,
+        scene_activated: impl Fn(&str, &str) -> bool,
+    ) -> Self {
+        Self(
+            categories
+                .iter()
+                .map(|category| {
+                    let checks = category
+                        .checks
+                        .iter()
+                        .map(|check| {
+                            let checked = check.predicate.eval(player_data, &scene_activated);
+                            (check.id.to_owned(), CheckState::new(check.label, checked))
+                        })
+                        .collect();
+                    (category.name, checks)
+                })
+                .collect(),
+        )
+    }
+
+    /// Replaces the checks of a category that isn't known ahead of time.
+    /// `checks` are `(key, label, checked)`, ids are derived from the keys,
+    /// which should be what the save calls things so that ids don't change
+    /// along with the labels.
+    pub(crate) fn fill(
+        &mut self,
+        name: &str,
+        prefix: &str,
+        checks: impl IntoIterator<Item = (String, String, bool)>,
+    ) {
+        if let Some((_, category)) = self.0.iter_mut().find(|(category, _)| *category == name) {
+            *category = checks
+                .into_iter()
+                .map(|(key, label, checked)| {
+                    let id = format!("{prefix}.{}", snake_case(&key));
+                    (id, CheckState::new(&label, checked))
+                })
+                .collect();
+        }
+    }
+
+    /// Sets a check that the save can't tell with a [`Predicate`].
+    pub(crate) fn set(&mut self, name: &str, id: &str, checked: bool) {
+        if let Some(check) = self
+            .0
+            .iter_mut()
+            .find(|(category, _)| *category == name)
+            .and_then(|(_, checks)| checks.get_mut(id))
+        {
+            check.checked = checked;
+        }
+    }
+
+    /// Checks of the category with the given name.
+    #[must_use]
+    pub fn category(&self, name: &str) -> Option<&IndexMap<String, CheckState>> {
+        self.0
+            .iter()
+            .find(|(category, _)| *category == name)
+            .map(|(_, checks)| checks)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &IndexMap<String, CheckState>)> {
+        self.0.iter().map(|(name, checks)| (*name, checks))
+    }
+}
+
+// Serialized as a struct of categories, each of them a map of checks by id,
+// so both keep their declaration order.
+impl Serialize for Checklist {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Checklist", self.0.len())?;
+        for (name, checks) in &self.0 {
+            s.serialize_field(name, checks)?;
+        }
+        s.end()
+    }
+}
+
+/// `Rock Rollers` -> `rock_rollers`.
+fn snake_case(name: &str) -> String {
+    let mut id = String::new();
+    for c in name.chars().filter(|&c| c != '\'') {
+        if c.is_ascii_alphanumeric() {
+            id.push(c.to_ascii_lowercase());
+        } else if !id.is_empty() && !id.ends_with('_') {
+            id.push('_');
+        }
+    }
+    id.trim_end_matches('_').to_owned()
+}
@@ -0,0 +1,192 @@
+use aes::{
+    Aes256,
+    cipher::{BlockDecryptMut, BlockEncryptMut, KeyInit, block_padding::Pkcs7},
+};
+use base64::prelude::*;
+
+use crate::ParseError;
+
+const KEY: &[u8; 32] = b"UKu52ePUBwetZ9wNX88o54dnfKRu0T1l";
+const CSHARP_HEADER: [u8; 22] = [
+    0x00, 0x01, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x06, 0x01, 0x00, 0x00, 0x00,
+];
+/// BinaryFormatter `MessageEnd` record.
+const MESSAGE_END: u8 = 0x0b;
+/// A 7-bit encoded `Int32` never takes more than 5 bytes.
+const MAX_LENGTH_BYTES: usize = 5;
+
+/// Turns the contents of a `user<N>.dat` file into the save's JSON.
+pub fn decode(data: &[u8]) -> Result<Vec<u8>, ParseError> {
+    // Remove last useless byte 0x0B and C# header
+    let dat = match data.split_last() {
+        Some((&MESSAGE_END, dat)) => dat,
+        Some((&byte, _)) => return Err(ParseError::InvalidTrailingByte(byte)),
+        None => return Err(ParseError::InvalidHeader),
+    };
+    let dat = dat
+        .strip_prefix(CSHARP_HEADER.as_slice())
+        .ok_or(ParseError::InvalidHeader)?;
+    // Remove length header
+    let (length, dat) = read_length(dat)?;
+    if dat.len() != length {
+        return Err(ParseError::BadLength(format!(
+            "header declares {length} bytes, found {}",
+            dat.len()
+        )));
+    }
+
+    let dat = BASE64_STANDARD
+        .decode(dat)
+        .map_err(|e| ParseError::Base64(e.to_string()))?;
+
+    // Decrypt
+    ecb::Decryptor::<Aes256>::new(KEY.into())
+        .decrypt_padded_vec_mut::<Pkcs7>(&dat)
+        .map_err(|e| ParseError::Decrypt(e.to_string()))
+}
+
+/// Reads the 7-bit encoded string length BinaryFormatter puts in front of the
+/// payload, returning it along with the rest of the data.
+fn read_length(data: &[u8]) -> Result<(usize, &[u8]), ParseError> {
+    let mut length = 0u64;
+    for (i, &byte) in data.iter().enumerate().take(MAX_LENGTH_BYTES) {
+        length |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            let length = i32::try_from(length)
+                .ok()
+                .and_then(|l| usize::try_from(l).ok())
+                .ok_or_else(|| ParseError::BadLength(format!("{length} is out of range")))?;
+            return Ok((length, &data[i + 1..]));
+        }
+    }
+
+    if data.len() < MAX_LENGTH_BYTES {
+        Err(ParseError::BadLength(
+            "length header is truncated".to_owned(),
+        ))
+    } else {
+        Err(ParseError::BadLength(
+            "length header is too long".to_owned(),
+        ))
+    }
+}
+
+/// Turns save JSON into the contents of a `user<N>.dat` file the game can
+/// load. This is the exact inverse of [`decode`].
+#[must_use]
+pub fn encode(json: &[u8]) -> Vec<u8> {
+    // Encrypt
+    let dat = ecb::Encryptor::<Aes256>::new(KEY.into()).encrypt_padded_vec_mut::<Pkcs7>(json);
+    let dat = BASE64_STANDARD.encode(dat);
+
+    let mut out = Vec::with_capacity(CSHARP_HEADER.len() + 5 + dat.len() + 1);
+    out.extend_from_slice(&CSHARP_HEADER);
+    // Length header, 7 bits per byte, least significant group first
+    let mut length = dat.len();
+    while length >= 0x80 {
+        #[allow(clippy::cast_possible_truncation)]
+        out.push(length as u8 | 0x80);
+        length >>= 7;
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    out.push(length as u8);
+    out.extend_from_slice(dat.as_bytes());
+    out.push(MESSAGE_END);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn save(length: &[u8], payload: &[u8]) -> Vec<u8> {
+        [&CSHARP_HEADER, length, payload, &[MESSAGE_END]].concat()
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = br#"{"playerData":{}}"#;
+        assert_eq!(decode(&encode(json)).unwrap(), json);
+    }
+
+    #[test]
+    fn length_spans_several_bytes() {
+        let json = [b' '; 1000];
+        let data = encode(&json);
+        // 1008 encrypted bytes, 1344 base64 characters
+        assert_eq!(data[CSHARP_HEADER.len()..][..2], [0xc0, 0x0a]);
+        assert_eq!(decode(&data).unwrap(), json);
+    }
+
+    #[test]
+    fn invalid_header() {
+        let mut data = encode(b"{}");
+        data[0] = 0xff;
+        assert_eq!(decode(&data), Err(ParseError::InvalidHeader));
+        assert_eq!(decode(&[]), Err(ParseError::InvalidHeader));
+        assert_eq!(decode(&[MESSAGE_END]), Err(ParseError::InvalidHeader));
+    }
+
+    #[test]
+    fn invalid_trailing_byte() {
+        let mut data = encode(b"{}");
+        data.pop();
+        assert_eq!(decode(&data), Err(ParseError::InvalidTrailingByte(b'=')));
+        assert_eq!(
+            decode(&CSHARP_HEADER),
+            Err(ParseError::InvalidTrailingByte(0x00))
+        );
+    }
+
+    #[test]
+    fn missing_length() {
+        assert_eq!(
+            decode(&save(&[], &[])),
+            Err(ParseError::BadLength(
+                "length header is truncated".to_owned()
+            ))
+        );
+        assert_eq!(
+            decode(&save(&[0x80, 0x80], &[])),
+            Err(ParseError::BadLength(
+                "length header is truncated".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn length_mismatch() {
+        assert_eq!(
+            decode(&save(&[3], b"ab")),
+            Err(ParseError::BadLength(
+                "header declares 3 bytes, found 2".to_owned()
+            ))
+        );
+        // A printable character where the length should be is the payload
+        // with the length header missing
+        assert_eq!(
+            decode(&save(&[], b"AAAA")),
+            Err(ParseError::BadLength(
+                "header declares 65 bytes, found 3".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn length_too_long() {
+        assert_eq!(
+            decode(&save(&[0x80; MAX_LENGTH_BYTES + 1], &[])),
+            Err(ParseError::BadLength(
+                "length header is too long".to_owned()
+            ))
+        );
+        assert_eq!(
+            decode(&save(&[0xff, 0xff, 0xff, 0xff, 0x0f], &[])),
+            Err(ParseError::BadLength(format!(
+                "{} is out of range",
+                u32::MAX
+            )))
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    Number,
+    checks::{CategoryDef, Checklist},
+};
+
+/// Completion percentage of a save, broken down by checklist category.
+#[derive(Serialize, Debug, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Completion {
+    /// Percentage computed from the save's progress.
+    pub percent: Number,
+    /// Percentage the game itself stored in `playerData.completionPercentage`.
+    pub reported: Number,
+    /// `reported - percent`, zero when the game agrees with us.
+    pub discrepancy: Number,
+    pub categories: Vec<CategoryCompletion>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryCompletion {
+    pub category: &'static str,
+    pub percent: Number,
+    pub max: Number,
+}
+
+impl Completion {
+    /// Sums up the completion the same way the game's
+    /// `PlayerData.CountGameCompletion` does, and compares it with what the
+    /// game saved. Checks are counted from `checks`, unless the game counts
+    /// them differently, see [`crate::CheckDef::counted_as`].
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn new(
+        categories: &[CategoryDef],
+        checks: &Checklist,
+        player_data: &Value,
+        reported: Number,
+    ) -> Self {
+        let categories: Vec<_> = categories
+            .iter()
+            .filter(|category| category.percent > 0.0)
+            .map(|category| {
+                let done = checks.category(category.name);
+                let counted = || category.checks.iter().filter(|check| check.percent);
+                let checked = counted()
+                    .filter(|check| match check.counted_as {
+                        Some(predicate) => predicate.eval(player_data, &|_, _| false),
+                        None => done
+                            .and_then(|d| d.get(check.id))
+                            .is_some_and(|c| c.checked),
+                    })
+                    .count();
+                CategoryCompletion {
+                    category: category.name,
+                    percent: (checked / category.group) as Number * category.percent,
+                    max: (counted().count() / category.group) as Number * category.percent,
+                }
+            })
+            .collect();
+
+        let percent = categories.iter().map(|c| c.percent).sum();
+        Self {
+            percent,
+            reported,
+            discrepancy: reported - percent,
+            categories,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use serde::{Deserialize, de::IgnoredAny};
+use std::{collections::HashMap, fmt};
+
+use crate::{ParseError, from_json};
+
+/// Player data keys that only exist in Hollow Knight saves.
+const HOLLOW_KNIGHT_KEYS: &[&str] = &[
+    "hasDreamNail",
+    "killedHollowKnight",
+    "gotCharm_1",
+    "dreamOrbs",
+];
+
+/// Player data keys that only exist in Silksong saves.
+const SILKSONG_KEYS: &[&str] = &["Tools", "ToolEquips", "silkSpoolParts", "hasNeedolin"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Game {
+    HollowKnight,
+    Silksong,
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::HollowKnight => "Hollow Knight",
+            Self::Silksong => "Silksong",
+        })
+    }
+}
+
+/// Which game a save belongs to, and the key that gave it away.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detection {
+    pub game: Game,
+    pub key: &'static str,
+    /// Game version the save was last written by, if it says.
+    pub version: Option<String>,
+}
+
+impl Detection {
+    /// Whether the save comes from a game version this crate can read.
+    /// Hollow Knight saves from before the Godmaster update (1.4) lack the
+    /// Godhome fields; every Silksong version is supported.
+    #[must_use]
+    pub fn is_supported_version(&self) -> bool {
+        let Some(version) = &self.version else {
+            return true;
+        };
+        let mut parts = version.split('.').map(str::parse::<u32>);
+        match (self.game, parts.next(), parts.next()) {
+            (Game::HollowKnight, Some(Ok(major)), Some(Ok(minor))) => (major, minor) >= (1, 4),
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for Detection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (found `playerData.{}`)", self.game, self.key)?;
+        if let Some(version) = &self.version {
+            write!(f, ", version {version}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Probe {
+    player_data: PlayerDataProbe,
+}
+
+#[derive(Deserialize)]
+struct PlayerDataProbe {
+    version: Option<String>,
+    #[serde(flatten)]
+    keys: HashMap<String, IgnoredAny>,
+}
+
+/// Figures out which game the decrypted save JSON belongs to without
+/// committing to either model, so field errors can be reported against the
+/// right one.
+///
+/// # Errors
+///
+/// Returns [`ParseError::Json`] if the save has no `playerData` object and
+/// [`ParseError::UnknownGame`] if its keys don't point at exactly one game.
+pub fn detect_game(json: &[u8]) -> Result<Detection, ParseError> {
+    let probe: Probe = from_json(json)?;
+    let pd = probe.player_data;
+    let find = |game, keys: &[&'static str]| {
+        keys.iter()
+            .find(|&&key| pd.keys.contains_key(key))
+            .map(|&key| Detection {
+                game,
+                key,
+                version: pd.version.clone(),
+            })
+    };
+
+    match (
+        find(Game::HollowKnight, HOLLOW_KNIGHT_KEYS),
+        find(Game::Silksong, SILKSONG_KEYS),
+    ) {
+        (Some(detection), None) | (None, Some(detection)) => Ok(detection),
+        (Some(hk), Some(ss)) => Err(ParseError::UnknownGame(format!(
+            "save has both `playerData.{}` and `playerData.{}`",
+            hk.key, ss.key
+        ))),
+        (None, None) => Err(ParseError::UnknownGame(
+            "no game specific `playerData` keys found".to_owned(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(player_data: &str) -> Result<Detection, ParseError> {
+        detect_game(format!(r#"{{"playerData":{player_data}}}"#).as_bytes())
+    }
+
+    fn version(game: Game, version: &str) -> Detection {
+        Detection {
+            game,
+            key: "",
+            version: Some(version.to_owned()),
+        }
+    }
+
+    #[test]
+    fn detects_each_game() {
+        let detection = detect(r#"{"version":"1.5.78.11833","gotCharm_1":true}"#).unwrap();
+        assert_eq!(detection.game, Game::HollowKnight);
+        assert_eq!(detection.key, "gotCharm_1");
+        assert_eq!(detection.version.as_deref(), Some("1.5.78.11833"));
+
+        let detection = detect(r#"{"hasNeedolin":false}"#).unwrap();
+        assert_eq!(detection.game, Game::Silksong);
+        assert_eq!(detection.version, None);
+    }
+
+    #[test]
+    fn keys_of_both_games() {
+        assert_eq!(
+            detect(r#"{"hasDreamNail":true,"Tools":{}}"#),
+            Err(ParseError::UnknownGame(
+                "save has both `playerData.hasDreamNail` and `playerData.Tools`".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn keys_of_neither_game() {
+        assert_eq!(
+            detect(r#"{"version":"1.5.78.11833","geo":0}"#),
+            Err(ParseError::UnknownGame(
+                "no game specific `playerData` keys found".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn hollow_knight_before_godmaster_is_unsupported() {
+        assert!(!version(Game::HollowKnight, "1.3.1.5").is_supported_version());
+        assert!(version(Game::HollowKnight, "1.4.3.2").is_supported_version());
+        assert!(version(Game::HollowKnight, "1.5.78.11833").is_supported_version());
+        assert!(version(Game::HollowKnight, "unknown").is_supported_version());
+        assert!(version(Game::Silksong, "1.0.28324").is_supported_version());
+    }
+}
@@ -0,0 +1,157 @@
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+use crate::SaveData;
+
+/// What changed between two saves of the same game, e.g. before and after a
+/// route segment.
+#[derive(Serialize, Debug, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveDiff {
+    /// Checks that flipped, by category, in checklist order.
+    pub checks: IndexMap<&'static str, Vec<CheckChange>>,
+    /// `playerData` fields whose value changed, by dotted path.
+    pub player_data: Vec<FieldChange>,
+    /// `persistentBoolItems` entries that weren't activated before.
+    pub activated: Vec<SceneItem>,
+}
+
+/// A check that flipped.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckChange {
+    pub id: String,
+    pub label: String,
+    /// State in the newer save.
+    pub checked: bool,
+}
+
+/// A changed `playerData` field. Fields only one of the saves has are
+/// `null` in the other.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    /// Dotted path like `bossDoorStateTier1.completed`. Lists are compared as
+    /// a whole.
+    pub path: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneItem {
+    pub scene_name: String,
+    pub id: String,
+}
+
+impl SaveDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty() && self.player_data.is_empty() && self.activated.is_empty()
+    }
+}
+
+/// Compares an older save with a newer one, `None` if they aren't from the
+/// same game.
+#[must_use]
+pub fn diff_saves(old: &SaveData, new: &SaveData) -> Option<SaveDiff> {
+    if old.game() != new.game() {
+        return None;
+    }
+
+    let old_checks = old.checks();
+    let checks = new
+        .checks()
+        .iter()
+        .filter_map(|(category, checks)| {
+            let before = old_checks.category(category);
+            let changes: Vec<_> = checks
+                .iter()
+                .filter(|(id, check)| {
+                    // Checks filled in from the save can be new
+                    let was = before.and_then(|b| b.get(*id)).is_some_and(|c| c.checked);
+                    was != check.checked
+                })
+                .map(|(id, check)| CheckChange {
+                    id: id.clone(),
+                    label: check.label.clone(),
+                    checked: check.checked,
+                })
+                .collect();
+            (!changes.is_empty()).then_some((category, changes))
+        })
+        .collect();
+
+    let mut player_data = Vec::new();
+    diff_fields("", &old.player_data(), &new.player_data(), &mut player_data);
+
+    let activated = match (old, new) {
+        (SaveData::HollowKnight(old), SaveData::HollowKnight(new)) => {
+            let was_activated: HashSet<_> = old
+                .scene_data
+                .persistent_bool_items
+                .iter()
+                .filter(|x| x.activated)
+                .map(|x| (x.scene_name.as_str(), x.id.as_str()))
+                .collect();
+            new.scene_data
+                .persistent_bool_items
+                .iter()
+                .filter(|x| {
+                    x.activated && !was_activated.contains(&(x.scene_name.as_str(), x.id.as_str()))
+                })
+                .map(|x| SceneItem {
+                    scene_name: x.scene_name.clone(),
+                    id: x.id.clone(),
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+
+    Some(SaveDiff {
+        checks,
+        player_data,
+        activated,
+    })
+}
+
+/// Collects the leaves that differ between two JSON values, recursing into
+/// objects only. Missing fields are `null`.
+fn diff_fields(path: &str, old: &Value, new: &Value, changes: &mut Vec<FieldChange>) {
+    let empty = Map::new();
+    match (fields(old, &empty), fields(new, &empty)) {
+        (Some(old_fields), Some(new_fields)) if old.is_object() || new.is_object() => {
+            let keys = old_fields
+                .keys()
+                .chain(new_fields.keys().filter(|k| !old_fields.contains_key(*k)));
+            for key in keys {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                let old = old_fields.get(key).unwrap_or(&Value::Null);
+                let new = new_fields.get(key).unwrap_or(&Value::Null);
+                diff_fields(&path, old, new, changes);
+            }
+        }
+        _ if old != new => changes.push(FieldChange {
+            path: path.to_owned(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn fields<'a>(value: &'a Value, empty: &'a Map<String, Value>) -> Option<&'a Map<String, Value>> {
+    match value {
+        Value::Object(fields) => Some(fields),
+        Value::Null => Some(empty),
+        _ => None,
+    }
+}
@@ -0,0 +1,466 @@
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value, json};
+
+use crate::{
+    EditError, Number, ParseError, SaveData, SceneObjectBool,
+    checks::{CategoryDef, CheckDef, Checklist, Predicate},
+    codec,
+    hollow_knight::{self, SaveFile},
+    parse, silksong,
+};
+
+/// Edits a parsed save and writes it back as a `user<N>.dat` the game can
+/// load, e.g. to prepare practice saves.
+///
+/// Every edit is checked against the game's model, so fields can only be set
+/// to values the parser would read back.
+#[derive(Debug, Clone)]
+pub struct SaveEditor {
+    save: SaveData,
+    /// JSON the save was read from. Edits are patched into it, so that the
+    /// rest stays the way the game wrote it, like `50` rather than `50.0`
+    /// for a float or fields the model fills in when they're missing.
+    source: Vec<u8>,
+    /// The model's JSON before any edit, to tell what the edits changed.
+    base: Value,
+}
+
+impl SaveEditor {
+    #[must_use]
+    pub fn new(save: SaveData) -> Self {
+        let source = to_vec(&save);
+        Self::with_source(save, source)
+    }
+
+    /// Reads a `user<N>.dat` file of either game.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn from_save_file(data: &[u8]) -> Result<Self, ParseError> {
+        let (_, save) = parse(data)?;
+        Ok(Self::with_source(save, codec::decode(data)?))
+    }
+
+    fn with_source(save: SaveData, source: Vec<u8>) -> Self {
+        let base = to_value(&save);
+        Self { save, source, base }
+    }
+
+    /// The save with every edit so far.
+    #[must_use]
+    pub fn save(&self) -> &SaveData {
+        &self.save
+    }
+
+    #[must_use]
+    pub fn into_save(self) -> SaveData {
+        self.save
+    }
+
+    /// `playerData.<path>`, nested fields and list items separated by dots
+    /// like `bossDoorStateTier1.completed` or `equippedCharms.0`.
+    #[must_use]
+    pub fn player_data(&self, path: &str) -> Option<Value> {
+        field(&mut self.save.player_data(), path).map(Value::take)
+    }
+
+    /// Sets `playerData.<path>`, see [`Self::player_data`]. The field has to
+    /// exist and keep its type.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn set_player_data(&mut self, path: &str, value: Value) -> Result<(), EditError> {
+        self.edit_player_data(|json| {
+            let old = field(json, path).ok_or_else(|| EditError::UnknownField(path.to_owned()))?;
+            let (expected, found) = (json_type(old), json_type(&value));
+            // Whole numbers are fine where the game stores floats
+            if expected != found && !(expected == "a number" && found == "an integer") {
+                return Err(EditError::TypeMismatch {
+                    path: path.to_owned(),
+                    expected,
+                    found,
+                });
+            }
+            *old = value;
+            Ok(())
+        })
+    }
+
+    /// Makes the check with the given id checked, changing whatever the save
+    /// needs for it along with the counters the game keeps next to it, like
+    /// `charmsOwned` for a charm or `maxHealth` for a fourth mask shard.
+    ///
+    /// Nothing changes if the check is already checked, or if it can't be
+    /// granted, which is an error.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn grant_check(&mut self, id: &str) -> Result<(), EditError> {
+        self.set_check(id, true)
+    }
+
+    /// Makes the check with the given id unchecked, see
+    /// [`Self::grant_check`].
+    #[allow(clippy::missing_errors_doc)]
+    pub fn revoke_check(&mut self, id: &str) -> Result<(), EditError> {
+        self.set_check(id, false)
+    }
+
+    /// Sets whether `(scene_name, id)` is activated in
+    /// `sceneData.persistentBoolItems`, adding the entry if the player hasn't
+    /// been there yet.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn set_scene_item(
+        &mut self,
+        scene_name: &str,
+        id: &str,
+        activated: bool,
+    ) -> Result<(), EditError> {
+        let items = &mut self
+            .hollow_knight("persistentBoolItems")?
+            .scene_data
+            .persistent_bool_items;
+        match items
+            .iter_mut()
+            .find(|x| x.scene_name == scene_name && x.id == id)
+        {
+            Some(item) => item.activated = activated,
+            None => items.push(SceneObjectBool {
+                id: id.to_owned(),
+                scene_name: scene_name.to_owned(),
+                activated,
+                // Written by the game for every entry
+                other: Map::from_iter([("semiPersistent".to_owned(), Value::Bool(false))]),
+            }),
+        }
+        Ok(())
+    }
+
+    fn set_check(&mut self, id: &str, checked: bool) -> Result<(), EditError> {
+        let categories = match &self.save {
+            SaveData::HollowKnight(_) => hollow_knight::CHECKS,
+            SaveData::Silksong(_) => silksong::CHECKS,
+        };
+        let (category, check) =
+            find_check(categories, id).ok_or_else(|| EditError::UnknownCheck(id.to_owned()))?;
+        // Achievements follow from the rest of the save, setting their flags
+        // alone would leave it claiming things it doesn't have
+        if category.derived {
+            return Err(EditError::Unsupported(format!(
+                "`{id}` follows from the rest of the save, edit that instead"
+            )));
+        }
+        let category = category.name;
+        if self.is_checked(category, id) == checked {
+            return Ok(());
+        }
+
+        // Leave the save alone if any part of the edit fails
+        let before = self.save.clone();
+        let result = self.apply(&check.predicate, checked).and_then(|()| {
+            if let SaveData::HollowKnight(save) = &mut self.save {
+                sync_counters(save, category, if checked { 1 } else { -1 })?;
+            }
+            if self.is_checked(category, id) == checked {
+                Ok(())
+            } else {
+                Err(EditError::Unsupported(format!(
+                    "`{id}` can't be {}",
+                    if checked { "granted" } else { "revoked" }
+                )))
+            }
+        });
+        if result.is_err() {
+            self.save = before;
+        }
+        result
+    }
+
+    fn is_checked(&self, category: &str, id: &str) -> bool {
+        is_checked(&self.save.checks(), category, id)
+    }
+
+    /// Changes the save until `predicate` is `checked`.
+    ///
+    /// Granting `All` grants every part, revoking it only revokes the last
+    /// one: that's what the check adds on top of the others, like Dream Gate
+    /// on top of the awoken Dream Nail. Granting `Any` grants its first part.
+    fn apply(&mut self, predicate: &Predicate, checked: bool) -> Result<(), EditError> {
+        match *predicate {
+            Predicate::Flag(path) => self.set_player_data(path, Value::Bool(checked)),
+            Predicate::AtLeast(path, n) => self.set_number(path, checked, n, |v| v >= n),
+            Predicate::Equals(path, n) => self.set_number(path, checked, n, |v| v == n),
+            Predicate::Below(path, _) => Err(EditError::Unsupported(format!(
+                "`{path}` isn't something to edit"
+            ))),
+            Predicate::Pieces {
+                pieces,
+                whole,
+                base,
+                per,
+                n,
+            } => {
+                let number = |path| self.player_data(path).as_ref().and_then(Value::as_f64);
+                let current =
+                    number(pieces).ok_or_else(|| EditError::UnknownField(pieces.to_owned()))?;
+                if predicate.eval(&self.save.player_data(), &|_, _| false) == checked {
+                    return Ok(());
+                }
+                let total = if checked { n } else { n - 1.0 };
+                // Keep counting the way the save does, restarting at every
+                // whole item unless the counter already went past one
+                let running = current >= per;
+                #[allow(clippy::cast_possible_truncation)]
+                let (pieces_value, whole_value) = (
+                    if running { total } else { total % per } as i64,
+                    (base + (total / per).floor()) as i64,
+                );
+                self.set_player_data(whole, Value::from(whole_value))?;
+                self.set_player_data(pieces, Value::from(pieces_value))
+            }
+            Predicate::Scene(scene_name, id) => self.set_scene_item(scene_name, id, checked),
+            Predicate::Unlocked(list, name) => self.set_unlocked(list, name, checked),
+            Predicate::All(predicates) => match predicates.split_last() {
+                Some((last, _)) if !checked => self.apply(last, false),
+                _ => predicates.iter().try_for_each(|p| self.apply(p, true)),
+            },
+            Predicate::Any(predicates) => match predicates.first() {
+                Some(first) if checked => self.apply(first, true),
+                _ => predicates.iter().try_for_each(|p| self.apply(p, false)),
+            },
+            Predicate::Derived => Err(EditError::Unsupported(
+                "the check follows from the rest of the save, edit that instead".to_owned(),
+            )),
+        }
+    }
+
+    /// Sets the number at `playerData.<path>` to `n` if `holds` should, or
+    /// to `n - 1` if it shouldn't, unless it already agrees.
+    fn set_number(
+        &mut self,
+        path: &str,
+        checked: bool,
+        n: Number,
+        holds: impl Fn(Number) -> bool,
+    ) -> Result<(), EditError> {
+        let current = self.player_data(path).as_ref().and_then(Value::as_f64);
+        let current = current.ok_or_else(|| EditError::UnknownField(path.to_owned()))?;
+        if holds(current) == checked {
+            return Ok(());
+        }
+        let value = if checked { n } else { n - 1.0 };
+        // Integer fields only take integers, the others don't mind
+        #[allow(clippy::cast_possible_truncation)]
+        let value = if value.fract() == 0.0 {
+            Value::from(value as i64)
+        } else {
+            Value::from(value)
+        };
+        self.set_player_data(path, value)
+    }
+
+    /// Sets `IsUnlocked` of the `name` entry of a Unity `savedData` list,
+    /// adding the entry when unlocking something the save hasn't seen.
+    fn set_unlocked(&mut self, list: &str, name: &str, unlocked: bool) -> Result<(), EditError> {
+        self.edit_player_data(|json| {
+            let entries = field(json, list)
+                .and_then(|list| list.get_mut("savedData"))
+                .and_then(Value::as_array_mut)
+                .ok_or_else(|| EditError::UnknownField(list.to_owned()))?;
+            match entries.iter_mut().find(|e| e["Name"] == name) {
+                Some(entry) => entry["Data"]["IsUnlocked"] = Value::Bool(unlocked),
+                None if unlocked => entries.push(json!({
+                    "Name": name,
+                    "Data": { "IsUnlocked": true },
+                })),
+                None => {}
+            }
+            Ok(())
+        })
+    }
+
+    /// Edits player data through the model's JSON and reads it back, so the
+    /// model decides what's valid.
+    fn edit_player_data(
+        &mut self,
+        f: impl FnOnce(&mut Value) -> Result<(), EditError>,
+    ) -> Result<(), EditError> {
+        match &mut self.save {
+            SaveData::HollowKnight(save) => edit(&mut save.player_data, f),
+            SaveData::Silksong(save) => edit(&mut save.player_data, f),
+        }
+    }
+
+    /// The save's JSON, as the game would write it: the JSON it was read
+    /// from with the edited fields changed. Keys come out sorted once
+    /// anything changed, the game doesn't mind their order.
+    #[must_use]
+    pub fn to_json(&self) -> Vec<u8> {
+        let edited = to_value(&self.save);
+        if edited == self.base {
+            return self.source.clone();
+        }
+        let Ok(mut json) = serde_json::from_slice(&self.source) else {
+            return to_vec(&self.save);
+        };
+        patch(&mut json, &self.base, &edited);
+        serde_json::to_vec(&json).unwrap_or_default()
+    }
+
+    /// The save as a `user<N>.dat` file, see [`crate::encode_save_file`].
+    #[must_use]
+    pub fn to_save_file(&self) -> Vec<u8> {
+        codec::encode(&self.to_json())
+    }
+
+    pub(crate) fn hollow_knight(&mut self, what: &str) -> Result<&mut SaveFile, EditError> {
+        match &mut self.save {
+            SaveData::HollowKnight(save) => Ok(save),
+            SaveData::Silksong(_) => Err(EditError::Unsupported(format!(
+                "Silksong saves have no {what}"
+            ))),
+        }
+    }
+}
+
+fn to_value(save: &SaveData) -> Value {
+    match save {
+        SaveData::HollowKnight(save) => serde_json::to_value(save),
+        SaveData::Silksong(save) => serde_json::to_value(save),
+    }
+    .unwrap_or_default()
+}
+
+fn to_vec(save: &SaveData) -> Vec<u8> {
+    serde_json::to_vec(&to_value(save)).unwrap_or_default()
+}
+
+/// Changes `json` where `new` differs from `old`, leaving everything else as
+/// it is in `json`.
+fn patch(json: &mut Value, old: &Value, new: &Value) {
+    if old == new {
+        return;
+    }
+    match (json, old, new) {
+        (Value::Object(json), Value::Object(old), Value::Object(new)) => {
+            json.retain(|key, _| new.contains_key(key) || !old.contains_key(key));
+            for (key, new) in new {
+                match (json.get_mut(key), old.get(key)) {
+                    // Filled in by the model and left alone
+                    (_, Some(old)) if old == new => {}
+                    (Some(json), Some(old)) => patch(json, old, new),
+                    _ => {
+                        json.insert(key.clone(), new.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(json), Value::Array(old), Value::Array(new))
+            if json.len() == old.len() && old.len() == new.len() =>
+        {
+            for ((json, old), new) in json.iter_mut().zip(old).zip(new) {
+                patch(json, old, new);
+            }
+        }
+        (json, _, new) => *json = new.clone(),
+    }
+}
+
+/// The value at a dotted path, list items by index.
+fn field<'a>(json: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    path.split('.').try_fold(json, |v, key| match v {
+        Value::Array(items) => key.parse().ok().and_then(|i: usize| items.get_mut(i)),
+        _ => v.get_mut(key),
+    })
+}
+
+fn edit<T: Serialize + DeserializeOwned>(
+    data: &mut T,
+    f: impl FnOnce(&mut Value) -> Result<(), EditError>,
+) -> Result<(), EditError> {
+    let mut json = serde_json::to_value(&*data).map_err(|e| EditError::Invalid {
+        path: String::new(),
+        message: e.to_string(),
+    })?;
+    f(&mut json)?;
+    *data = serde_path_to_error::deserialize(json).map_err(|e| EditError::Invalid {
+        path: e.path().to_string(),
+        message: e.inner().to_string(),
+    })?;
+    Ok(())
+}
+
+fn find_check<'a>(categories: &'a [CategoryDef], id: &str) -> Option<(&'a CategoryDef, CheckDef)> {
+    categories.iter().find_map(|category| {
+        let check = category.checks.iter().find(|check| check.id == id)?;
+        Some((category, *check))
+    })
+}
+
+fn is_checked(checks: &Checklist, category: &str, id: &str) -> bool {
+    checks
+        .category(category)
+        .and_then(|checks| checks.get(id))
+        .is_some_and(|check| check.checked)
+}
+
+/// Updates the counters the game keeps next to a Hollow Knight category after
+/// one of its checks was granted (`delta` 1) or revoked (-1).
+fn sync_counters(save: &mut SaveFile, category: &str, delta: i32) -> Result<(), EditError> {
+    let data = &mut save.player_data;
+    match category {
+        "maskShards" => {
+            let shards = ((data.max_health_base - 5) * 4 + data.heart_pieces + delta).clamp(0, 16);
+            let base = 5 + shards / 4;
+            data.max_health += base - data.max_health_base;
+            data.max_health_base = base;
+            data.heart_pieces = shards % 4;
+            // The game heals the knight on every new mask
+            data.health = data.max_health;
+        }
+        "vesselFragments" => {
+            let fragments =
+                (data.mp_reserve_max / 33 * 3 + data.vessel_fragments + delta).clamp(0, 9);
+            data.mp_reserve_max = fragments / 3 * 33;
+            data.vessel_fragments = fragments % 3;
+            data.mp_reserve = data.mp_reserve.min(data.mp_reserve_max);
+        }
+        "grubs" => data.grubs_collected = (data.grubs_collected + delta).max(0),
+        "charms" => {
+            let json = serde_json::to_value(&*data).unwrap_or_default();
+            let flag = |name: &str| json.get(name).and_then(Value::as_bool);
+            // Unlike the others, this one is always the number of charms
+            let mut owned = 0;
+            for charm in 1..=40 {
+                let equipped = format!("equippedCharm_{charm}");
+                if flag(&format!("gotCharm_{charm}")).unwrap_or(false) {
+                    owned += 1;
+                } else if flag(&equipped).unwrap_or(false) || data.equipped_charms.contains(&charm)
+                {
+                    // Take off charms that aren't owned anymore
+                    let cost = json
+                        .get(format!("charmCost_{charm}"))
+                        .and_then(Value::as_i64);
+                    let cost = cost.and_then(|c| i32::try_from(c).ok()).unwrap_or(0);
+                    data.equipped_charms.retain(|&c| c != charm);
+                    data.charm_slots_filled = (data.charm_slots_filled - cost).max(0);
+                    if flag(&equipped).is_some() {
+                        edit(data, |json| {
+                            json[equipped] = Value::Bool(false);
+                            Ok(())
+                        })?;
+                    }
+                }
+            }
+            data.charms_owned = owned;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(n) if n.is_f64() => "a number",
+        Value::Number(_) => "an integer",
+        Value::String(_) => "a string",
+        Value::Array(_) => "a list",
+        Value::Object(_) => "an object",
+    }
+}
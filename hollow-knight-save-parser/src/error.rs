@@ -0,0 +1,153 @@
+#[cfg(target_arch = "wasm32")]
+use serde::Serialize;
+use std::fmt;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsValue;
+
+/// Why a `user<N>.dat` file couldn't be read, from its BinaryFormatter
+/// framing down to JSON that doesn't fit the detected game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The file doesn't start with the BinaryFormatter header.
+    InvalidHeader,
+    /// The file doesn't end with the BinaryFormatter `MessageEnd` record.
+    InvalidTrailingByte(u8),
+    /// The 7-bit encoded length prefix is malformed or doesn't match the
+    /// payload.
+    BadLength(String),
+    Base64(String),
+    Decrypt(String),
+    /// The decrypted JSON doesn't match the detected game's model.
+    Json {
+        path: String,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The save doesn't look like either game's.
+    UnknownGame(String),
+    /// The save was made by a game version whose format isn't supported.
+    UnsupportedVersion(String),
+}
+
+impl ParseError {
+    /// Name of the variant as the web app sees it, see `ParseErrorKind` in
+    /// `src/types/checklist.ts`.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidHeader => "invalidHeader",
+            Self::InvalidTrailingByte(_) => "invalidTrailingByte",
+            Self::BadLength(_) => "badLength",
+            Self::Base64(_) => "base64",
+            Self::Decrypt(_) => "decrypt",
+            Self::Json { .. } => "json",
+            Self::UnknownGame(_) => "unknownGame",
+            Self::UnsupportedVersion(_) => "unsupportedVersion",
+        }
+    }
+
+    pub(crate) fn json(e: &serde_path_to_error::Error<serde_json::Error>) -> Self {
+        Self::Json {
+            path: e.path().to_string(),
+            line: e.inner().line(),
+            column: e.inner().column(),
+            message: e.inner().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHeader => f.write_str("Invalid header: not a BinaryFormatter save file"),
+            Self::InvalidTrailingByte(byte) => {
+                write!(f, "Invalid trailing byte: expected 0x0b, found {byte:#04x}")
+            }
+            Self::BadLength(e) => write!(f, "Invalid length: {e}"),
+            Self::Base64(e) => write!(f, "Base64 decode error: {e}"),
+            Self::Decrypt(e) => write!(f, "Decryption error: {e}"),
+            Self::Json { path, message, .. } => {
+                write!(f, "JSON parse error at `{path}`: {message}")
+            }
+            Self::UnknownGame(e) => write!(f, "Game detection error: {e}"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported game version: {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Why a [`SaveEditor`](crate::SaveEditor) turned down an edit. A failed
+/// edit leaves the save as it was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditError {
+    /// The save has no field at this path.
+    UnknownField(String),
+    /// No check of the save's game has this id. Checks filled in from the
+    /// save, like geo rocks, can't be edited.
+    UnknownCheck(String),
+    /// The new value isn't the same JSON type as the one in the save.
+    TypeMismatch {
+        path: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// The edited save no longer fits the game's model, e.g. a number out of
+    /// range.
+    Invalid { path: String, message: String },
+    /// The edit doesn't apply to this game's saves, or to the check, like an
+    /// achievement that follows from other checks.
+    Unsupported(String),
+}
+
+impl EditError {
+    /// Name of the variant, camelCase like [`ParseError::kind`].
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::UnknownField(_) => "unknownField",
+            Self::UnknownCheck(_) => "unknownCheck",
+            Self::TypeMismatch { .. } => "typeMismatch",
+            Self::Invalid { .. } => "invalid",
+            Self::Unsupported(_) => "unsupported",
+        }
+    }
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField(path) => write!(f, "Unknown field `{path}`"),
+            Self::UnknownCheck(id) => write!(f, "Unknown check `{id}`"),
+            Self::TypeMismatch {
+                path,
+                expected,
+                found,
+            } => write!(f, "`{path}` is {expected}, not {found}"),
+            Self::Invalid { path, message } => write!(f, "Invalid value at `{path}`: {message}"),
+            Self::Unsupported(e) => write!(f, "Unsupported edit: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
+#[cfg(target_arch = "wasm32")]
+impl From<ParseError> for JsValue {
+    fn from(e: ParseError) -> Self {
+        #[derive(Serialize)]
+        struct JsParseError {
+            kind: &'static str,
+            message: String,
+        }
+
+        let error = JsParseError {
+            kind: e.kind(),
+            message: e.to_string(),
+        };
+        serde_wasm_bindgen::to_value(&error).unwrap_or_else(|_| JsValue::from_str(&error.message))
+    }
+}
@@ -0,0 +1,2933 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use crate::{
+    AchievementProgress, Completion, Number, Statistics,
+    checks::{
+        CategoryDef, CheckDef, Checklist,
+        Predicate::{self, All, Any, AtLeast, Below, Derived, Equals, Flag, Scene},
+    },
+};
+
+pub type HollowKnightChecks = Checklist;
+
+const fn check(id: &'static str, label: &'static str, predicate: Predicate) -> CheckDef {
+    CheckDef::new(id, label, predicate)
+}
+
+/// The game counts masks rather than the shards that make them up, so every
+/// four shards count once the knight has `n` extra masks.
+const fn masks(n: Number) -> Predicate {
+    AtLeast("maxHealthBase", 5.0 + n)
+}
+
+/// Same as [`masks`] for soul vessels, 33 soul each.
+const fn vessels(n: Number) -> Predicate {
+    AtLeast("MPReserveMax", 33.0 * n)
+}
+
+/// Every Hollow Knight check, in the order the checklist lists them.
+pub const CHECKS: &[CategoryDef] = &[
+    CategoryDef::new("bosses", BOSSES).percent(1.0, 1),
+    CategoryDef::new("optionalBosses", OPTIONAL_BOSSES),
+    CategoryDef::new("equipment", EQUIPMENT).percent(2.0, 1),
+    CategoryDef::new("nail", NAIL).percent(1.0, 1),
+    CategoryDef::new("dreamNail", DREAM_NAIL).percent(1.0, 1),
+    CategoryDef::new("nailArts", NAIL_ARTS).percent(1.0, 1),
+    CategoryDef::new("spells", SPELLS).percent(1.0, 1),
+    CategoryDef::new("charms", CHARMS).percent(1.0, 1),
+    CategoryDef::new("maskShards", MASK_SHARDS).percent(1.0, 4),
+    CategoryDef::new("vesselFragments", VESSEL_FRAGMENTS).percent(1.0, 3),
+    CategoryDef::new("dreamers", DREAMERS).percent(1.0, 1),
+    CategoryDef::new("dreamWarriors", DREAM_WARRIORS).percent(1.0, 1),
+    CategoryDef::new("dreamBosses", DREAM_BOSSES),
+    CategoryDef::new("colosseum", COLOSSEUM).percent(1.0, 1),
+    CategoryDef::new("godhome", GODHOME).percent(1.0, 1),
+    CategoryDef::new("grubs", GRUBS),
+    CategoryDef::new("items", ITEMS),
+    CategoryDef::new("relics", RELICS),
+    CategoryDef::new("whisperingRoots", WHISPERING_ROOTS),
+    // Only rocks in scenes the player has been to are saved.
+    CategoryDef::new("geoRocks", &[]).derived(),
+    // Every enemy with journal fields in the save, see `SaveFile::journal`.
+    CategoryDef::new("huntersJournal", &[]).derived(),
+    CategoryDef::new("achievements", ACHIEVEMENTS).derived(),
+];
+
+const BOSSES: &[CheckDef] = &[
+    check(
+        "boss.broken_vessel",
+        "[Broken Vessel]",
+        Flag("killedInfectedKnight"),
+    ),
+    check(
+        "boss.brooding_mawlek",
+        "[Brooding Mawlek]",
+        Flag("killedMawlek"),
+    ),
+    check(
+        "boss.the_collector",
+        "[The Collector]",
+        Flag("killedJarCollector"),
+    ),
+    check(
+        "boss.dung_defender",
+        "[Dung Defender]",
+        Flag("defeatedDungDefender"),
+    ),
+    check(
+        "boss.false_knight",
+        "[False Knight]",
+        Flag("falseKnightDefeated"),
+    ),
+    check("boss.grimm", "[Grimm]", Flag("killedGrimm")),
+    check("boss.gruz_mother", "[Gruz Mother]", Flag("killedBigFly")),
+    check(
+        "boss.hive_knight",
+        "[Hive Knight]",
+        Flag("killedHiveKnight"),
+    ),
+    check(
+        "boss.hornet_protector",
+        "[Hornet Protector]",
+        Flag("hornet1Defeated"),
+    ),
+    check(
+        "boss.hornet_sentinel",
+        "[Hornet Sentinel]",
+        Flag("hornetOutskirtsDefeated"),
+    ),
+    check(
+        "boss.mantis_lords",
+        "[Mantis Lords]",
+        Flag("defeatedMantisLords"),
+    ),
+    check("boss.nosk", "[Nosk]", Flag("killedMimicSpider")),
+    check(
+        "boss.soul_master",
+        "[Soul Master]",
+        Flag("mageLordDefeated"),
+    ),
+    check(
+        "boss.traitor_lord",
+        "[Traitor Lord]",
+        Flag("killedTraitorLord"),
+    ),
+    check("boss.uumuu", "[Uumuu]", Flag("defeatedMegaJelly")),
+    check(
+        "boss.watcher_knight",
+        "[Watcher Knight]",
+        Flag("killedBlackKnight"),
+    ),
+];
+
+const OPTIONAL_BOSSES: &[CheckDef] = &[
+    check(
+        "optional_boss.absolute_radiance",
+        "[Absolute Radiance]",
+        Flag("bossDoorStateTier5.completed"),
+    ),
+    check(
+        "optional_boss.brothers_oro_and_mato",
+        "[Brothers Oro & Mato]",
+        Flag("killedNailBros"),
+    ),
+    check(
+        "optional_boss.crystal_guardian",
+        "[Crystal Guardian]",
+        Flag("killedMegaBeamMiner"),
+    ),
+    check(
+        "optional_boss.enraged_guardian",
+        "[Enraged Guardian]",
+        Scene("Mines_32", "Zombie Beam Miner Rematch"),
+    ),
+    check(
+        "optional_boss.flukemarm",
+        "[Flukemarm]",
+        Flag("killedFlukeman"),
+    ),
+    check(
+        "optional_boss.god_tamer",
+        "[God Tamer]",
+        Flag("killedLobsterLancer"),
+    ),
+    check(
+        "optional_boss.great_nailsage_sly",
+        "[Great Nailsage Sly]",
+        Flag("killedNailsage"),
+    ),
+    check(
+        "optional_boss.hollow_knight",
+        "[Hollow Knight]",
+        Flag("killedHollowKnight"),
+    ),
+    check(
+        "optional_boss.massive_moss_charger",
+        "[Massive Moss Charger]",
+        Flag("killedMegaMossCharger"),
+    ),
+    check(
+        "optional_boss.oblobbles",
+        "[Oblobbles]",
+        Flag("killedOblobble"),
+    ),
+    check(
+        "optional_boss.paintmaster_sheo",
+        "[Paintmaster Sheo]",
+        Flag("killedPaintmaster"),
+    ),
+    check(
+        "optional_boss.pure_vessel",
+        "[Pure Vessel]",
+        Flag("killedHollowKnightPrime"),
+    ),
+    check(
+        "optional_boss.radiance",
+        "[Radiance]",
+        Flag("killedFinalBoss"),
+    ),
+    check(
+        "optional_boss.sisters_of_battle",
+        "[Sisters of Battle]",
+        Flag("bossDoorStateTier5.completed"),
+    ),
+    check(
+        "optional_boss.soul_warrior",
+        "[Soul Warrior]",
+        Flag("killedMageKnight"),
+    ),
+    check(
+        "optional_boss.vengefly_king",
+        "[Vengefly King]",
+        Flag("killedBigBuzzer"),
+    ),
+    check(
+        "optional_boss.winged_nosk",
+        "[Winged Nosk]",
+        Flag("bossDoorStateTier5.completed"),
+    ),
+    check(
+        "optional_boss.zote",
+        "[Zote]",
+        Flag("colosseumBronzeCompleted"),
+    ),
+];
+
+const EQUIPMENT: &[CheckDef] = &[
+    check(
+        "equipment.crystal_heart",
+        "[Crystal Heart]",
+        Flag("hasSuperDash"),
+    ),
+    check(
+        "equipment.ismas_tear",
+        "[Isma's Tear]",
+        Flag("hasAcidArmour"),
+    ),
+    check(
+        "equipment.mantis_claw",
+        "[Mantis Claw]",
+        Flag("hasWalljump"),
+    ),
+    check(
+        "equipment.monarch_wings",
+        "[Monarch Wings]",
+        Flag("hasDoubleJump"),
+    ),
+    check(
+        "equipment.mothwing_cloak",
+        "[Mothwing Cloak]",
+        Flag("hasDash"),
+    ),
+    check(
+        "equipment.shade_cloak",
+        "[Shade Cloak]",
+        Flag("hasShadowDash"),
+    ),
+    check(
+        "equipment.kings_brand",
+        "[King's Brand]",
+        Flag("hasKingsBrand"),
+    ),
+];
+
+const NAIL: &[CheckDef] = &[
+    check(
+        "nail.sharpened_nail",
+        "[Sharpened Nail](Nail#Upgrades)",
+        AtLeast("nailSmithUpgrades", 1.0),
+    ),
+    check(
+        "nail.channelled_nail",
+        "[Channelled Nail](Nail#Upgrades)",
+        AtLeast("nailSmithUpgrades", 2.0),
+    ),
+    check(
+        "nail.coiled_nail",
+        "[Coiled Nail](Nail#Upgrades)",
+        AtLeast("nailSmithUpgrades", 3.0),
+    ),
+    check(
+        "nail.pure_nail",
+        "[Pure Nail](Nail#Upgrades)",
+        AtLeast("nailSmithUpgrades", 4.0),
+    ),
+];
+
+const DREAM_NAIL: &[CheckDef] = &[
+    check(
+        "dream_nail.dream_nail",
+        "[Dream Nail]",
+        Flag("hasDreamNail"),
+    ),
+    check(
+        "dream_nail.awoken_dream_nail",
+        "[Awoken Dream Nail]",
+        All(&[Flag("dreamNailUpgraded"), Flag("hasDreamGate")]),
+    )
+    .counted_as(Flag("dreamNailUpgraded")),
+    check(
+        "dream_nail.ascension",
+        "[Ascension](Seer)",
+        Flag("mothDeparted"),
+    ),
+];
+
+const NAIL_ARTS: &[CheckDef] = &[
+    check(
+        "nail_art.cyclone_slash",
+        "[Cyclone Slash]",
+        Flag("hasCyclone"),
+    ),
+    check(
+        "nail_art.dash_slash",
+        "[Dash Slash]",
+        Flag("hasUpwardSlash"),
+    ), // lol team cherry
+    check(
+        "nail_art.great_slash",
+        "[Great Slash]",
+        Flag("hasDashSlash"),
+    ), // lol team cherry
+];
+
+const SPELLS: &[CheckDef] = &[
+    check(
+        "spell.desolate_dive",
+        "[Desolate Dive]",
+        AtLeast("quakeLevel", 1.0),
+    ),
+    check(
+        "spell.descending_dark",
+        "[Descending Dark]",
+        AtLeast("quakeLevel", 2.0),
+    ),
+    check(
+        "spell.howling_wraiths",
+        "[Howling Wraiths]",
+        AtLeast("screamLevel", 1.0),
+    ),
+    check(
+        "spell.abyss_shriek",
+        "[Abyss Shriek]",
+        AtLeast("screamLevel", 2.0),
+    ),
+    check(
+        "spell.vengeful_spirit",
+        "[Vengeful Spirit]",
+        AtLeast("fireballLevel", 1.0),
+    ),
+    check(
+        "spell.shade_soul",
+        "[Shade Soul]",
+        AtLeast("fireballLevel", 2.0),
+    ),
+];
+
+const CHARMS: &[CheckDef] = &[
+    check(
+        "charm.wayward_compass",
+        "[Wayward Compass]",
+        Flag("gotCharm_2"),
+    ),
+    check(
+        "charm.gathering_swarm",
+        "[Gathering Swarm]",
+        Flag("gotCharm_1"),
+    ),
+    check(
+        "charm.stalwart_shell",
+        "[Stalwart Shell]",
+        Flag("gotCharm_4"),
+    ),
+    check("charm.soul_catcher", "[Soul Catcher]", Flag("gotCharm_20")),
+    check("charm.shaman_stone", "[Shaman Stone]", Flag("gotCharm_19")),
+    check("charm.soul_eater", "[Soul Eater]", Flag("gotCharm_21")),
+    check("charm.dashmaster", "[Dashmaster]", Flag("gotCharm_31")),
+    check("charm.sprintmaster", "[Sprintmaster]", Flag("gotCharm_37")),
+    check("charm.grubsong", "[Grubsong]", Flag("gotCharm_3")),
+    check(
+        "charm.grubberflys_elegy",
+        "[Grubberfly's Elegy]",
+        Flag("gotCharm_35"),
+    ),
+    check(
+        "charm.fragile_heart",
+        "[Fragile Heart] / [Unbreakable Heart]",
+        Flag("gotCharm_23"),
+    ),
+    check(
+        "charm.fragile_greed",
+        "[Fragile Greed] / [Unbreakable Greed]",
+        Flag("gotCharm_24"),
+    ),
+    check(
+        "charm.fragile_strength",
+        "[Fragile Strength] / [Unbreakable Strength]",
+        Flag("gotCharm_25"),
+    ),
+    check(
+        "charm.spell_twister",
+        "[Spell Twister]",
+        Flag("gotCharm_33"),
+    ),
+    check("charm.steady_body", "[Steady Body]", Flag("gotCharm_14")),
+    check("charm.heavy_blow", "[Heavy Blow]", Flag("gotCharm_15")),
+    check("charm.quick_slash", "[Quick Slash]", Flag("gotCharm_32")),
+    check("charm.longnail", "[Longnail]", Flag("gotCharm_18")),
+    check(
+        "charm.mark_of_pride",
+        "[Mark of Pride]",
+        Flag("gotCharm_13"),
+    ),
+    check(
+        "charm.fury_of_the_fallen",
+        "[Fury of the Fallen]",
+        Flag("gotCharm_6"),
+    ),
+    check(
+        "charm.thorns_of_agony",
+        "[Thorns of Agony]",
+        Flag("gotCharm_12"),
+    ),
+    check("charm.baldur_shell", "[Baldur Shell]", Flag("gotCharm_5")),
+    check("charm.flukenest", "[Flukenest]", Flag("gotCharm_11")),
+    check(
+        "charm.defenders_crest",
+        "[Defender's Crest]",
+        Flag("gotCharm_10"),
+    ),
+    check("charm.glowing_womb", "[Glowing Womb]", Flag("gotCharm_22")),
+    check("charm.quick_focus", "[Quick Focus]", Flag("gotCharm_7")),
+    check("charm.deep_focus", "[Deep Focus]", Flag("gotCharm_34")),
+    check(
+        "charm.lifeblood_heart",
+        "[Lifeblood Heart]",
+        Flag("gotCharm_8"),
+    ),
+    check(
+        "charm.lifeblood_core",
+        "[Lifeblood Core]",
+        Flag("gotCharm_9"),
+    ),
+    check(
+        "charm.jonis_blessing",
+        "[Joni's Blessing]",
+        Flag("gotCharm_27"),
+    ),
+    check("charm.hiveblood", "[Hiveblood]", Flag("gotCharm_29")),
+    check("charm.spore_shroom", "[Spore Shroom]", Flag("gotCharm_17")),
+    check("charm.sharp_shadow", "[Sharp Shadow]", Flag("gotCharm_16")),
+    check("charm.shape_of_unn", "[Shape of Unn]", Flag("gotCharm_28")),
+    check(
+        "charm.nailmasters_glory",
+        "[Nailmaster's Glory]",
+        Flag("gotCharm_26"),
+    ),
+    check("charm.weaversong", "[Weaversong]", Flag("gotCharm_39")),
+    check(
+        "charm.dream_wielder",
+        "[Dream Wielder]",
+        Flag("gotCharm_30"),
+    ),
+    check("charm.dreamshield", "[Dreamshield]", Flag("gotCharm_38")),
+    check(
+        "charm.grimmchild",
+        "[Grimmchild] / [Carefree Melody]",
+        Flag("gotCharm_40"),
+    ),
+    check(
+        "charm.kingsoul",
+        "[Kingsoul] / [Void Heart]",
+        All(&[
+            Flag("gotCharm_36"),
+            Flag("gotKingFragment"),
+            Flag("gotQueenFragment"),
+        ]),
+    )
+    // The game already counts the White Fragment as a charm
+    .counted_as(Flag("gotCharm_36")),
+];
+
+const MASK_SHARDS: &[CheckDef] = &[
+    check("mask_shard.sly_1", "[Sly] #1", Flag("slyShellFrag1")).counted_as(masks(1.0)),
+    check("mask_shard.sly_2", "[Sly] #2", Flag("slyShellFrag2")).counted_as(masks(1.0)),
+    check("mask_shard.sly_3", "[Sly] #3", Flag("slyShellFrag3")).counted_as(masks(1.0)),
+    check("mask_shard.sly_4", "[Sly] #4", Flag("slyShellFrag4")).counted_as(masks(1.0)),
+    check(
+        "mask_shard.forgotten_crossroads_brooding_mawlek",
+        "[Forgotten Crossroads] [Brooding Mawlek]",
+        Scene("Crossroads_09", "Heart Piece"),
+    )
+    .counted_as(masks(2.0)),
+    check(
+        "mask_shard.grubfather",
+        "[Grubfather]",
+        Scene("Crossroads_38", "Heart Piece"),
+    )
+    .counted_as(masks(2.0)),
+    check(
+        "mask_shard.forgotten_crossroads_goams",
+        "[Forgotten Crossroads] [Goams]",
+        Scene("Crossroads_13", "Heart Piece"),
+    )
+    .counted_as(masks(2.0)),
+    check(
+        "mask_shard.queens_station",
+        "[Queen's Station]",
+        Scene("Fungus2_01", "Heart Piece"),
+    )
+    .counted_as(masks(2.0)),
+    check(
+        "mask_shard.brettas_house",
+        "[Bretta]'s house",
+        Scene("Room_Bretta", "Heart Piece"),
+    )
+    .counted_as(masks(3.0)),
+    check(
+        "mask_shard.stone_sanctuary",
+        "[Stone Sanctuary]",
+        Scene("Fungus1_36", "Heart Piece"),
+    )
+    .counted_as(masks(3.0)),
+    check(
+        "mask_shard.royal_waterways",
+        "[Royal Waterways]",
+        Scene("Waterways_04b", "Heart Piece"),
+    )
+    .counted_as(masks(3.0)),
+    check(
+        "mask_shard.deepnest_from_fungal_core",
+        "[Deepnest] from [Fungal Core]",
+        Scene("Fungus2_25", "Heart Piece"),
+    )
+    .counted_as(masks(3.0)),
+    check(
+        "mask_shard.enraged_guardian",
+        "[Enraged Guardian]",
+        Scene("Mines_32", "Heart Piece"),
+    )
+    .counted_as(masks(4.0)),
+    check(
+        "mask_shard.the_hive",
+        "[The Hive]",
+        Scene("Hive_04", "Heart Piece"),
+    )
+    .counted_as(masks(4.0)),
+    check("mask_shard.seer", "[Seer]", Flag("dreamReward7")).counted_as(masks(4.0)),
+    check(
+        "mask_shard.grey_mourner",
+        "[Grey Mourner]",
+        Scene("Room_Mansion", "Heart Piece"),
+    )
+    .counted_as(masks(4.0)),
+];
+
+const VESSEL_FRAGMENTS: &[CheckDef] = &[
+    check("vessel_fragment.sly_1", "[Sly] #1", Flag("slyVesselFrag1")).counted_as(vessels(1.0)),
+    check("vessel_fragment.sly_2", "[Sly] #2", Flag("slyVesselFrag2")).counted_as(vessels(1.0)),
+    check(
+        "vessel_fragment.greenpath",
+        "[Greenpath]",
+        Scene("Fungus1_13", "Vessel Fragment"),
+    )
+    .counted_as(vessels(1.0)),
+    check(
+        "vessel_fragment.left_of_the_lift_in_forgotten_crossroads",
+        "Left of the lift in [Forgotten Crossroads]",
+        Scene("Crossroads_37", "Vessel Fragment"),
+    )
+    .counted_as(vessels(2.0)),
+    check(
+        "vessel_fragment.above_kings_station_near_a_lift",
+        "Above [King's Station] near a lift",
+        Scene("Ruins2_09", "Vessel Fragment"),
+    )
+    .counted_as(vessels(2.0)),
+    check(
+        "vessel_fragment.deepnest",
+        "[Deepnest]",
+        Scene("Deepnest_38", "Vessel Fragment"),
+    )
+    .counted_as(vessels(2.0)),
+    check(
+        "vessel_fragment.stag_nest",
+        "[Stag Nest]",
+        Flag("vesselFragStagNest"),
+    )
+    .counted_as(vessels(3.0)),
+    check("vessel_fragment.seer", "[Seer]", Flag("dreamReward5")).counted_as(vessels(3.0)),
+    check(
+        "vessel_fragment.ancient_basin_fountain",
+        "[Ancient Basin] fountain",
+        Scene("Abyss_04", "Vessel Fragment"),
+    )
+    .counted_as(vessels(3.0)),
+];
+
+const DREAMERS: &[CheckDef] = &[
+    check(
+        "dreamer.herra_the_beast",
+        "[Herra the Beast]",
+        Flag("hegemolDefeated"),
+    ),
+    check(
+        "dreamer.lurien_the_watcher",
+        "[Lurien the Watcher]",
+        Flag("lurienDefeated"),
+    ),
+    check(
+        "dreamer.monomon_the_teacher",
+        "[Monomon the Teacher]",
+        Flag("monomonDefeated"),
+    ),
+];
+
+const DREAM_WARRIORS: &[CheckDef] = &[
+    check(
+        "dream_warrior.elder_hu",
+        "[Elder Hu]",
+        Equals("elderHuDefeated", 2.0),
+    ),
+    check(
+        "dream_warrior.galien",
+        "[Galien]",
+        Equals("galienDefeated", 2.0),
+    ),
+    check(
+        "dream_warrior.gorb",
+        "[Gorb]",
+        Equals("aladarSlugDefeated", 2.0),
+    ),
+    check(
+        "dream_warrior.markoth",
+        "[Markoth]",
+        Equals("markothDefeated", 2.0),
+    ),
+    check(
+        "dream_warrior.marmu",
+        "[Marmu]",
+        Equals("mumCaterpillarDefeated", 2.0),
+    ),
+    check(
+        "dream_warrior.no_eyes",
+        "[No Eyes]",
+        Equals("noEyesDefeated", 2.0),
+    ),
+    check("dream_warrior.xero", "[Xero]", Equals("xeroDefeated", 2.0)),
+    check(
+        "dream_warrior.nightmare_king_grimm",
+        "[Nightmare King Grimm] / [Banishment](Grimm Troupe (Quest))",
+        Any(&[Flag("killedNightmareGrimm"), Flag("nymmInTown")]),
+    )
+    .counted_as(Any(&[
+        Flag("killedNightmareGrimm"),
+        Flag("destroyedNightmareLantern"),
+    ])),
+];
+
+const DREAM_BOSSES: &[CheckDef] = &[
+    check(
+        "dream_boss.failed_champion",
+        "[Failed Champion]",
+        Flag("falseKnightDreamDefeated"),
+    ),
+    check(
+        "dream_boss.grey_prince_zote",
+        "[Grey Prince Zote]",
+        Flag("greyPrinceDefeated"),
+    ),
+    check(
+        "dream_boss.lost_kin",
+        "[Lost Kin]",
+        Flag("infectedKnightDreamDefeated"),
+    ),
+    check(
+        "dream_boss.white_defender",
+        "[White Defender]",
+        Flag("whiteDefenderDefeated"),
+    ),
+    check(
+        "dream_boss.soul_tyrant",
+        "[Soul Tyrant]",
+        Flag("mageLordDreamDefeated"),
+    ),
+];
+
+const COLOSSEUM: &[CheckDef] = &[
+    check(
+        "colosseum.trial_of_the_warrior",
+        "[Trial of the Warrior]",
+        Flag("colosseumBronzeCompleted"),
+    ),
+    check(
+        "colosseum.trial_of_the_conqueror",
+        "[Trial of the Conqueror]",
+        Flag("colosseumSilverCompleted"),
+    ),
+    check(
+        "colosseum.trial_of_the_fool",
+        "[Trial of the Fool]",
+        Flag("colosseumGoldCompleted"),
+    ),
+];
+
+const GODHOME: &[CheckDef] = &[
+    check("godhome.godtuner", "[Godtuner]", Flag("hasGodfinder")),
+    check(
+        "godhome.pantheon_of_the_master",
+        "[Pantheon of the Master]",
+        Flag("bossDoorStateTier1.completed"),
+    ),
+    check(
+        "godhome.pantheon_of_the_artist",
+        "[Pantheon of the Artist]",
+        Flag("bossDoorStateTier2.completed"),
+    ),
+    check(
+        "godhome.pantheon_of_the_sage",
+        "[Pantheon of the Sage]",
+        Flag("bossDoorStateTier3.completed"),
+    ),
+    check(
+        "godhome.pantheon_of_the_knight",
+        "[Pantheon of the Knight]",
+        Flag("bossDoorStateTier4.completed"),
+    ),
+    check(
+        "godhome.pantheon_of_the_hallownest",
+        "[Pantheon of the Hallownest] (no percent)",
+        Flag("bossDoorStateTier5.completed"),
+    )
+    .no_percent(),
+];
+
+const GRUBS: &[CheckDef] = &[
+    check(
+        "grub.forgotten_crossroads_behind_husk_guard",
+        "[Forgotten Crossroads] behind [Husk Guard]",
+        Scene("Crossroads_48", "Grub Bottle"),
+    ),
+    check(
+        "grub.forgotten_crossroads_fog_canyon_entrance",
+        "[Forgotten Crossroads] [Fog Canyon] entrance",
+        Scene("Crossroads_35", "Grub Bottle"),
+    ),
+    check(
+        "grub.forgotten_crossroads_breakable_wall",
+        "[Forgotten Crossroads] breakable wall",
+        Scene("Crossroads_03", "Grub Bottle"),
+    ),
+    check(
+        "grub.forgotten_crossroads_pogo",
+        "[Forgotten Crossroads] [Pogo](Nail#Nail-bouncing)",
+        Scene("Crossroads_31", "Grub Bottle"),
+    ),
+    check(
+        "grub.forgotten_crossroads_on_a_ledge",
+        "[Forgotten Crossroads] on a ledge",
+        Scene("Crossroads_05", "Grub Bottle"),
+    ),
+    check(
+        "grub.greenpath_with_a_moss_block_shortcut",
+        "[Greenpath] with a moss block shortcut",
+        Scene("Fungus1_06", "Grub Bottle"),
+    ),
+    check(
+        "grub.greenpath_near_acid",
+        "[Greenpath] near acid",
+        Scene("Fungus1_07", "Grub Bottle"),
+    ),
+    check(
+        "grub.greenpath_behind_moss_knight",
+        "[Greenpath] behind [Moss Knight]",
+        Scene("Fungus1_21", "Grub Bottle"),
+    ),
+    check(
+        "grub.greenpath_in_the_middle_of_a_durandoo_room",
+        "[Greenpath] in the middle of a [Durandoo] room",
+        Scene("Fungus1_13", "Grub Bottle"),
+    ),
+    check(
+        "grub.fungal_wastes_behind_a_line_of_funglings",
+        "[Fungal Wastes] behind a line of [Fungling]s",
+        Scene("Fungus2_18", "Grub Bottle"),
+    ),
+    check(
+        "grub.fungal_wastes_near_spore_shroom",
+        "[Fungal Wastes] near [Spore Shroom]",
+        Scene("Fungus2_20", "Grub Bottle"),
+    ),
+    check(
+        "grub.city_of_tears_on_a_ledge",
+        "[City of Tears] on a ledge",
+        Scene("Ruins1_05", "Grub Bottle (1)"),
+    ),
+    check(
+        "grub.city_of_tears_behind_great_husk_sentry",
+        "[City of Tears] behind [Great Husk Sentry]",
+        Scene("Ruins_House_01", "Grub Bottle"),
+    ),
+    check(
+        "grub.city_of_tears_in_the_desolate_dive_dive",
+        "[City of Tears] in the [Desolate Dive] dive",
+        Scene("Ruins1_32", "Grub Bottle"),
+    ),
+    check(
+        "grub.city_of_tears_under_the_entrance_to_the_tower_of_love",
+        "[City of Tears] under the entrance to the [Tower of Love]",
+        Scene("Ruins2_07", "Grub Bottle"),
+    ),
+    check(
+        "grub.city_of_tears_room_leading_to_watcher_knight",
+        "[City of Tears] room leading to [Watcher Knight]",
+        Scene("Ruins2_03", "Grub Bottle"),
+    ),
+    check(
+        "grub.crystal_peak_from_dirtmouth",
+        "[Crystal Peak] from [Dirtmouth]",
+        Scene("Mines_16", "Grub Bottle"),
+    ),
+    check(
+        "grub.crystal_peak_behind_presses",
+        "[Crystal Peak] behind presses",
+        Scene("Mines_19", "Grub Bottle"),
+    ),
+    check(
+        "grub.crystal_peak_near_crystal_heart",
+        "[Crystal Peak] near [Crystal Heart]",
+        Scene("Mines_31", "Grub Bottle"),
+    ),
+    check(
+        "grub.crystal_peak_on_the_way_to_hallownests_crown",
+        "[Crystal Peak] on the way to [Hallownest's Crown]",
+        Scene("Mines_24", "Grub Bottle"),
+    ),
+    check(
+        "grub.crystal_peak_vertical_conveyor_belts_lever",
+        "[Crystal Peak] vertical conveyor belts lever",
+        Scene("Mines_03", "Grub Bottle"),
+    ),
+    check(
+        "grub.crystal_peak_from_the_top_room_with_presses",
+        "[Crystal Peak] from the top room with presses",
+        Scene("Mines_04", "Grub Bottle"),
+    ),
+    check(
+        "grub.crystal_peak_in_the_crystallized_mound",
+        "[Crystal Peak] in the [Crystallized Mound]",
+        Scene("Mines_35", "Grub Bottle"),
+    ),
+    check(
+        "grub.resting_grounds_crypts",
+        "[Resting Grounds] [Crypts](Resting Grounds#Crypts)",
+        Scene("RestingGrounds_10", "Grub Bottle"),
+    ),
+    check(
+        "grub.royal_waterways_behind_a_wall_near_water",
+        "[Royal Waterways] behind a wall near water",
+        Scene("Waterways_04", "Grub Bottle"),
+    ),
+    check(
+        "grub.royal_waterways_from_the_kingdoms_edge",
+        "[Royal Waterways] from the [Kingdom's Edge]",
+        Scene("Waterways_14", "Grub Bottle"),
+    ),
+    check(
+        "grub.royal_waterways_above_ismas_tear",
+        "[Royal Waterways] above [Isma's Tear]",
+        Scene("Waterways_13", "Grub Bottle"),
+    ),
+    check(
+        "grub.howling_cliffs",
+        "[Howling Cliffs]",
+        Scene("Fungus1_28", "Grub Bottle"),
+    ),
+    check(
+        "grub.kingdoms_edge_under_oros_hut",
+        "[Kingdom's Edge] under [Oro]'s hut",
+        Scene("Deepnest_East_14", "Grub Bottle"),
+    ),
+    check(
+        "grub.kingdoms_edge_behind_a_primal_aspid",
+        "[Kingdom's Edge] behind a [Primal Aspid]",
+        Scene("Deepnest_East_11", "Grub Bottle"),
+    ),
+    check(
+        "grub.fog_canyon",
+        "[Fog Canyon]",
+        Scene("Fungus3_47", "Grub Bottle"),
+    ),
+    check(
+        "grub.queens_gardens_under_the_stag_station",
+        "[Queen's Gardens] under the [Stag] station",
+        Scene("Fungus3_10", "Grub Bottle"),
+    ),
+    check(
+        "grub.queens_gardens_above_the_spiky_roof",
+        "[Queen's Gardens] above the spiky roof",
+        Scene("Fungus3_22", "Grub Bottle"),
+    ),
+    check(
+        "grub.queens_gardens_near_white_lady",
+        "[Queen's Gardens] near [White Lady]",
+        Scene("Fungus3_48", "Grub Bottle"),
+    ),
+    check(
+        "grub.deepnest_among_grub_mimics",
+        "[Deepnest] among [Grub Mimic]s",
+        Scene("Deepnest_36", "Grub Bottle"),
+    ),
+    check(
+        "grub.deepnest_above_the_spike_pit",
+        "[Deepnest] above the spike pit",
+        Scene("Deepnest_03", "Grub Bottle"),
+    ),
+    check(
+        "grub.deepnest_on_the_way_to_nosk",
+        "[Deepnest] on the way to [Nosk]",
+        Scene("Deepnest_31", "Grub Bottle"),
+    ),
+    check(
+        "grub.deepnest_near_the_weavers_den",
+        "[Deepnest] near the [Weavers' Den]",
+        Scene("Deepnest_39", "Grub Bottle"),
+    ),
+    check(
+        "grub.deepnest_in_the_beasts_den",
+        "[Deepnest] in the [Beast's Den]",
+        Scene("Deepnest_Spider_Town", "Grub Bottle"),
+    ),
+    check(
+        "grub.ancient_basin_above_broken_vessel",
+        "[Ancient Basin] above [Broken Vessel]",
+        Scene("Abyss_19", "Grub Bottle"),
+    ),
+    check(
+        "grub.ancient_basin_under_cloth",
+        "[Ancient Basin] under [Cloth]",
+        Scene("Abyss_17", "Grub Bottle"),
+    ),
+    check(
+        "grub.the_hive_isolated_room",
+        "[The Hive] isolated room",
+        Scene("Hive_03", "Grub Bottle"),
+    ),
+    check(
+        "grub.the_hive",
+        "[The Hive]",
+        Scene("Hive_04", "Grub Bottle"),
+    ),
+    check(
+        "grub.tower_of_love_1",
+        "[Tower of Love] #1",
+        Scene("Ruins2_11", "Grub Bottle"),
+    ),
+    check(
+        "grub.tower_of_love_2",
+        "[Tower of Love] #2",
+        Scene("Ruins2_11", "Grub Bottle"),
+    ),
+    check(
+        "grub.tower_of_love_3",
+        "[Tower of Love] #3",
+        Scene("Ruins2_11", "Grub Bottle"),
+    ),
+];
+
+const ITEMS: &[CheckDef] = &[
+    check(
+        "item.simple_key.sly",
+        "[SIMPLE_KEY] [Simple Key] from [Sly]",
+        Flag("slySimpleKey"),
+    ),
+    check(
+        "item.simple_key.city_storerooms",
+        "[SIMPLE_KEY] [Simple Key] near [City Storerooms]",
+        Scene("Ruins1_17", "Shiny Item"),
+    ),
+    check(
+        "item.simple_key.ancient_basin",
+        "[SIMPLE_KEY] [Simple Key] in the [Ancient Basin]",
+        Scene("Abyss_20", "Shiny Item Stand"),
+    ),
+    check(
+        "item.simple_key.pale_lurker",
+        "[SIMPLE_KEY] [Simple Key] behind [Pale Lurker]",
+        Flag("gotLurkerKey"),
+    ),
+    check(
+        "item.elegant_key",
+        "[ELEGANT_KEY] [Elegant Key]",
+        Flag("hasWhiteKey"),
+    ),
+    check(
+        "item.love_key",
+        "[LOVE_KEY] [Love Key]",
+        Any(&[Flag("hasLoveKey"), Flag("openedLoveDoor")]),
+    ),
+    check(
+        "item.shopkeepers_key",
+        "[SHOPKEEPER'S_KEY] [Shopkeeper's Key]",
+        Any(&[Flag("hasSlykey"), Flag("gaveSlykey")]),
+    ),
+    check(
+        "item.tram_pass",
+        "[TRAM_PASS] [Tram Pass]",
+        Flag("hasTramPass"),
+    ),
+    check(
+        "item.lumafly_lantern",
+        "[Lumafly Lantern]",
+        Flag("hasLantern"),
+    ),
+    check(
+        "item.delicate_flower",
+        "[Delicate Flower]",
+        Flag("xunFlowerGiven"),
+    ),
+    check(
+        "item.pale_ore.ancient_basin_below_cloth",
+        "[PALE_ORE] [Pale Ore] in [Ancient Basin] below [Cloth]",
+        Scene("Abyss_17", "Battle Scene Ore"),
+    ),
+    check(
+        "item.pale_ore.seer",
+        "[PALE_ORE] [Pale Ore] awarded by the [Seer]",
+        Flag("dreamReward3"),
+    ),
+    check(
+        "item.pale_ore.hallownests_crown",
+        "[PALE_ORE] [Pale Ore] on the [Hallownest's Crown]",
+        Scene("Mines_34", "Shiny Item Stand"),
+    ),
+    check(
+        "item.pale_ore.nosks_lair",
+        "[PALE_ORE] [Pale Ore] behind [Nosk]s lair",
+        Scene("Deepnest_32", "Shiny Item Stand"),
+    ),
+    check(
+        "item.pale_ore.grubfather",
+        "[PALE_ORE] [Pale Ore] awarded by [Grubfather]",
+        Scene("Crossroads_38", "Shiny Item Ore"),
+    ),
+    check(
+        "item.pale_ore.trial_of_the_conqueror",
+        "[PALE_ORE] [Pale Ore] reward in [Trial of the Conqueror]",
+        Scene("Room_Colosseum_Silver", "Shiny Item"),
+    ),
+    check(
+        "item.charm_notch.salubra_1",
+        "[CHARM_NOTCH] [Charm Notch] from [Salubra] #1",
+        Flag("salubraNotch1"),
+    ),
+    check(
+        "item.charm_notch.salubra_2",
+        "[CHARM_NOTCH] [Charm Notch] from [Salubra] #2",
+        Flag("salubraNotch2"),
+    ),
+    check(
+        "item.charm_notch.salubra_3",
+        "[CHARM_NOTCH] [Charm Notch] from [Salubra] #3",
+        Flag("salubraNotch3"),
+    ),
+    check(
+        "item.charm_notch.salubra_4",
+        "[CHARM_NOTCH] [Charm Notch] from [Salubra] #4",
+        Flag("salubraNotch4"),
+    ),
+    check(
+        "item.charm_notch.fog_canyon",
+        "[CHARM_NOTCH] [Charm Notch] in [Fog Canyon]",
+        Flag("notchFogCanyon"),
+    ),
+    check(
+        "item.charm_notch.fungal_wastes",
+        "[CHARM_NOTCH] [Charm Notch] in [Fungal Wastes]",
+        Flag("notchShroomOgres"),
+    ),
+    check(
+        "item.charm_notch.colosseum_of_fools",
+        "[CHARM_NOTCH] [Charm Notch] from [Colosseum of Fools]",
+        Scene("Room_Colosseum_Bronze", "Shiny Item"),
+    ),
+    check(
+        "item.charm_notch.grimm",
+        "[CHARM_NOTCH] [Charm Notch] from [Grimm]",
+        Flag("gotGrimmNotch"),
+    ),
+];
+
+const RELICS: &[CheckDef] = &[
+    check(
+        "relic.journal.greenpath_near_a_stag_station",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Greenpath] near a [Stag Station]",
+        Scene("Fungus1_22", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.greenpath_near_fog_canyon_entrance",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Greenpath] near [Fog Canyon] entrance",
+        Scene("Fungus1_11", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.fungal_wastes_near_shrumal_ogres",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Fungal Wastes] near [Shrumal Ogre]s",
+        Scene("Fungus2_04", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.mantis_village",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] north of the [Mantis Village]",
+        Scene("Fungus2_17", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.city_storerooms",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [City Storerooms]",
+        Scene("Ruins1_28", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.kings_station",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] north of [King's Station]",
+        Scene("Deepnest_East_07", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.pleasure_house",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Pleasure House]",
+        Scene("Ruins_Elevator", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.howling_cliffs",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Howling Cliffs]",
+        Scene("Cliffs_01", "Shiny Item (1)"),
+    ),
+    check(
+        "relic.journal.crystal_peak",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Crystal Peak]",
+        Scene("Mines_20", "Shiny Item (1)"),
+    ),
+    check(
+        "relic.journal.crypts",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Crypts](Resting Grounds#Crypts)",
+        Scene("RestingGrounds_10", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.royal_waterways",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Royal Waterways]",
+        Scene("Abyss_02", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.city_of_tears_entrance",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] near [City of Tears] entrance",
+        Scene("Ruins2_05", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.cast_off_shell_bench",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] next to the [Cast-Off Shell] [Bench]",
+        Scene("Deepnest_East_13", "Shiny Item"),
+    ),
+    check(
+        "relic.journal.markoth",
+        "[WANDERER'S_JOURNAL] [Wanderer's Journal] near [Markoth]",
+        Scene("Deepnest_East_18", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.grubfather",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] awarded by [Grubfather]",
+        Scene("Crossroads_38", "Shiny Item Relic2"),
+    ),
+    check(
+        "relic.seal.well_to_forgotten_crossroads",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] in the well to [Forgotten Crossroads]",
+        Scene("Crossroads_01", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.thorns_of_agony",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] near [Thorns of Agony]",
+        Scene("Fungus1_10", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.queens_station",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] near [Queen's Station]",
+        Scene("Fungus2_03", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.mantis_village",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] in [Mantis Village]",
+        Scene("Fungus2_31", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.willoh",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] at the [Willoh]",
+        Scene("Fungus2_34", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.overgrown_mound",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] near [Overgrown Mound]",
+        Scene("Fungus3_30", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.forgotten_crossroads_in_fog_canyon_entrance",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] in [Forgotten Crossroads] in [Fog Canyon] entrance",
+        Scene("Fungus3_26", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.crypts",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] in [Crypts](Resting Grounds#Crypts)",
+        Scene("RestingGrounds_10", "Shiny Item (1)"),
+    ),
+    check(
+        "relic.seal.seer",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] awarded by the [Seer]",
+        Flag("dreamReward1"),
+    ),
+    check(
+        "relic.seal.relic_seeker_lemm",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] near [Relic Seeker Lemm]",
+        Scene("Ruins1_03", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.kings_station_stag_station",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] above [King's Station] [Stag Station]",
+        Scene("Ruins2_08", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.soul_master",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] near [Soul Master]",
+        Scene("Ruins1_32", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.watcher_knight",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] behind [Watcher Knight]",
+        Scene("Ruins2_03", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.beasts_den",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] in [Beast's Den]",
+        Scene("Deepnest_Spider_Town", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.deepnest_near_mantis_lords",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] in [Deepnest] near [Mantis Lords]",
+        Scene("Deepnest_16", "Shiny Item"),
+    ),
+    check(
+        "relic.seal.queens_gardens",
+        "[HALLOWNEST_SEAL] [Hallownest Seal] in [Queen's Gardens]",
+        Scene("Fungus3_48", "Shiny Item"),
+    ),
+    check(
+        "relic.idol.grubfather",
+        "[KING'S_IDOL] [King's Idol] awarded by [Grubfather]",
+        Scene("Crossroads_38", "Shiny Item Relic3"),
+    ),
+    check(
+        "relic.idol.crystal_peak",
+        "[KING'S_IDOL] [King's Idol] in [Crystal Peak]",
+        Scene("Mines_30", "Shiny Item Stand"),
+    ),
+    check(
+        "relic.idol.spirits_glade",
+        "[KING'S_IDOL] [King's Idol] in [Spirits' Glade]",
+        Scene("RestingGrounds_08", "Shiny Item"),
+    ),
+    check(
+        "relic.idol.dung_defenders_secret_room",
+        "[KING'S_IDOL] [King's Idol] in [Dung Defender]'s secret room",
+        Scene("Waterways_15", "Shiny Item Stand"),
+    ),
+    check(
+        "relic.idol.howling_cliffs",
+        "[KING'S_IDOL] [King's Idol] in [Howling Cliffs]",
+        Scene("Cliffs_01", "Shiny Item"),
+    ),
+    check(
+        "relic.idol.colosseum_of_fools",
+        "[KING'S_IDOL] [King's Idol] under [Colosseum of Fools]",
+        Scene("Deepnest_East_08", "Shiny Item"),
+    ),
+    check(
+        "relic.idol.pale_lurker",
+        "[KING'S_IDOL] [King's Idol] near [Pale Lurker]",
+        Scene("GG_Lurker", "Shiny Item"),
+    ),
+    check(
+        "relic.idol.deepnest_near_zote",
+        "[KING'S_IDOL] [King's Idol] in [Deepnest] near [Zote]",
+        Scene("Deepnest_33", "Shiny Item"),
+    ),
+    check(
+        "relic.egg.lifeblood_core",
+        "[ARCANE_EGG] [Arcane Egg] below [Lifeblood Core]",
+        Scene("Abyss_08", "Shiny Item (1)"),
+    ),
+    check(
+        "relic.egg.shade_cloak",
+        "[ARCANE_EGG] [Arcane Egg] near [Shade Cloak]",
+        Scene("Abyss_10", "Shiny Item"),
+    ),
+    check(
+        "relic.egg.birthplace",
+        "[ARCANE_EGG] [Arcane Egg] in [Birthplace]",
+        Scene("Abyss_15", "Shiny Item"),
+    ),
+    check(
+        "relic.egg.seer",
+        "[ARCANE_EGG] [Arcane Egg] awarded by the [Seer]",
+        Flag("dreamReward6"),
+    ),
+];
+
+const WHISPERING_ROOTS: &[CheckDef] = &[
+    check(
+        "whispering_root.ancestral_mound",
+        "[Ancestral Mound]",
+        Scene("Crossroads_ShamanTemple", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.city_of_tears",
+        "[City of Tears]",
+        Scene("Ruins1_17", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.crystal_peak",
+        "[Crystal Peak]",
+        Scene("Mines_23", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.deepnest",
+        "[Deepnest]",
+        Scene("Deepnest_39", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.forgotten_crossroads",
+        "[Forgotten Crossroads]",
+        Scene("Crossroads_07", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.fungal_wastes_near_fog_canyon",
+        "[Fungal Wastes] (near [Fog Canyon])",
+        Scene("Fungus2_33", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.fungal_wastes_above_mantis_village",
+        "[Fungal Wastes] (above [Mantis Village])",
+        Scene("Fungus2_17", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.greenpath",
+        "[Greenpath]",
+        Scene("Fungus1_13", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.the_hive",
+        "[The Hive]",
+        Scene("Hive_02", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.howling_cliffs",
+        "[Howling Cliffs]",
+        Scene("Cliffs_01", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.kingdoms_edge",
+        "[Kingdom's Edge]",
+        Scene("Deepnest_East_07", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.queens_gardens",
+        "[Queen's Gardens]",
+        Scene("Fungus3_11", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.resting_grounds",
+        "[Resting Grounds]",
+        Scene("RestingGrounds_05", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.royal_waterways",
+        "[Royal Waterways]",
+        Scene("Abyss_01", "Dream Plant"),
+    ),
+    check(
+        "whispering_root.spirits_glade",
+        "[Spirits' Glade]",
+        Scene("RestingGrounds_08", "Dream Plant"),
+    ),
+];
+
+/// The game gives ending achievements when the credits roll, a save that
+/// was played on after that may have had a lower play time back then.
+const ENDING: Predicate = Flag("killedHollowKnight");
+
+/// Steam achievements that can be told from the save. Those counting
+/// something also have progress, see [`SaveFile::achievement_progress`].
+const ACHIEVEMENTS: &[CheckDef] = &[
+    check(
+        "achievement.charmed",
+        "[Charmed](Achievements): Acquire a [Charm](Charms)",
+        AtLeast("charmsOwned", 1.0),
+    ),
+    check(
+        "achievement.enchanted",
+        "[Enchanted](Achievements): Acquire half of all [Charms]",
+        AtLeast("charmsOwned", 20.0),
+    ),
+    check(
+        "achievement.blessed",
+        "[Blessed](Achievements): Acquire all [Charms]",
+        AtLeast("charmsOwned", 40.0),
+    ),
+    check(
+        "achievement.protected",
+        "[Protected](Achievements): Acquire 4 [Mask Shards]",
+        AtLeast("maxHealthBase", 6.0),
+    ),
+    check(
+        "achievement.masked",
+        "[Masked](Achievements): Acquire all [Mask Shards]",
+        AtLeast("maxHealthBase", 9.0),
+    ),
+    check(
+        "achievement.soulful",
+        "[Soulful](Achievements): Acquire 3 [Vessel Fragments]",
+        AtLeast("MPReserveMax", 33.0),
+    ),
+    check(
+        "achievement.worldsoul",
+        "[Worldsoul](Achievements): Acquire all [Vessel Fragments]",
+        AtLeast("MPReserveMax", 99.0),
+    ),
+    check(
+        "achievement.grubfriend",
+        "[Grubfriend](Achievements): Rescue half of the [Grubs]",
+        AtLeast("grubsCollected", 23.0),
+    ),
+    check(
+        "achievement.metamorphosis",
+        "[Metamorphosis](Achievements): Rescue all the [Grubs]",
+        AtLeast("grubsCollected", 46.0),
+    ),
+    check(
+        "achievement.keen_hunter",
+        "[Keen Hunter](Achievements): Discover every creature in the [Hunter's Journal]",
+        // Set from the journal in `SaveFile::checks`
+        Derived,
+    ),
+    check(
+        "achievement.true_hunter",
+        "[True Hunter](Achievements): Complete the [Hunter's Journal]",
+        Flag("hasHuntersMark"),
+    ),
+    check(
+        "achievement.attunement",
+        "[Attunement](Achievements): Collect 600 [Essence]",
+        // The later gifts need more essence than that
+        Any(&[
+            AtLeast("dreamOrbs", 600.0),
+            Flag("dreamNailUpgraded"),
+            Flag("mothDeparted"),
+        ]),
+    ),
+    check(
+        "achievement.awakening",
+        "[Awakening](Achievements): Awaken the [Dream Nail]",
+        Flag("dreamNailUpgraded"),
+    ),
+    check(
+        "achievement.ascension",
+        "[Ascension](Achievements): Gain the [Seer]'s final gift",
+        Flag("mothDeparted"),
+    ),
+    check(
+        "achievement.falsehood",
+        "[Falsehood](Achievements): Defeat the [False Knight]",
+        Flag("falseKnightDefeated"),
+    ),
+    check(
+        "achievement.test_of_resolve",
+        "[Test of Resolve](Achievements): Defeat [Hornet] in [Greenpath]",
+        Flag("hornet1Defeated"),
+    ),
+    check(
+        "achievement.proof_of_resolve",
+        "[Proof of Resolve](Achievements): Defeat [Hornet] in [Kingdom's Edge]",
+        Flag("hornetOutskirtsDefeated"),
+    ),
+    check(
+        "achievement.teacher",
+        "[Teacher](Achievements): Destroy the seal of [Monomon the Teacher]",
+        Flag("monomonDefeated"),
+    ),
+    check(
+        "achievement.watcher",
+        "[Watcher](Achievements): Destroy the seal of [Lurien the Watcher]",
+        Flag("lurienDefeated"),
+    ),
+    check(
+        "achievement.beast",
+        "[Beast](Achievements): Destroy the seal of [Herrah the Beast]",
+        Flag("hegemolDefeated"),
+    ),
+    check(
+        "achievement.grimm",
+        "[Grimm](Achievements): Defeat [Troupe Master Grimm]",
+        Flag("killedGrimm"),
+    ),
+    check(
+        "achievement.nightmare_god",
+        "[Nightmare God](Achievements): Defeat the [Nightmare King]",
+        Flag("killedNightmareGrimm"),
+    ),
+    check(
+        "achievement.banishment",
+        "[Banishment](Achievements): Banish the [Grimm Troupe](Grimm Troupe (Quest))",
+        Flag("destroyedNightmareLantern"),
+    ),
+    check(
+        "achievement.warrior",
+        "[Warrior](Achievements): Complete the [Trial of the Warrior]",
+        Flag("colosseumBronzeCompleted"),
+    ),
+    check(
+        "achievement.conqueror",
+        "[Conqueror](Achievements): Complete the [Trial of the Conqueror]",
+        Flag("colosseumSilverCompleted"),
+    ),
+    check(
+        "achievement.fool",
+        "[Fool](Achievements): Complete the [Trial of the Fool]",
+        Flag("colosseumGoldCompleted"),
+    ),
+    check(
+        "achievement.hollow_knight",
+        "[Hollow Knight](Achievements): Defeat the [Hollow Knight](Hollow Knight (Boss))",
+        ENDING,
+    ),
+    check(
+        "achievement.sealed_siblings",
+        "[Sealed Siblings](Achievements): Complete the game with the [Void Heart]",
+        // The save doesn't say which ending was seen, only that the Void
+        // Heart was there for the latest one
+        All(&[ENDING, Flag("gotShadeCharm")]),
+    ),
+    check(
+        "achievement.dream_no_more",
+        "[Dream No More](Achievements): Defeat [The Radiance]",
+        Flag("killedFinalBoss"),
+    ),
+    check(
+        "achievement.completion",
+        "[Completion](Achievements): Achieve 100% game completion",
+        All(&[ENDING, AtLeast("completionPercentage", 100.0)]),
+    ),
+    check(
+        "achievement.speedrun_1",
+        "[Speedrun 1](Achievements): Beat the game in less than 10 hours",
+        All(&[ENDING, Below("playTime", 10.0 * 3600.0)]),
+    ),
+    check(
+        "achievement.speedrun_2",
+        "[Speedrun 2](Achievements): Beat the game in less than 5 hours",
+        All(&[ENDING, Below("playTime", 5.0 * 3600.0)]),
+    ),
+    check(
+        "achievement.speed_completion",
+        "[Speed Completion](Achievements): Achieve 100% completion in less than 20 hours",
+        All(&[
+            ENDING,
+            AtLeast("completionPercentage", 100.0),
+            Below("playTime", 20.0 * 3600.0),
+        ]),
+    ),
+    check(
+        "achievement.steel_soul",
+        "[Steel Soul](Achievements): Beat the game in [Steel Soul] mode",
+        // A Steel Soul save that died afterwards still has it
+        All(&[ENDING, AtLeast("permadeathMode", 1.0)]),
+    ),
+    check(
+        "achievement.brotherhood",
+        "[Brotherhood](Achievements): Complete the [Pantheon of the Master]",
+        Flag("bossDoorStateTier1.completed"),
+    ),
+    check(
+        "achievement.inspiration",
+        "[Inspiration](Achievements): Complete the [Pantheon of the Artist]",
+        Flag("bossDoorStateTier2.completed"),
+    ),
+    check(
+        "achievement.focus",
+        "[Focus](Achievements): Complete the [Pantheon of the Sage]",
+        Flag("bossDoorStateTier3.completed"),
+    ),
+    check(
+        "achievement.soul_and_shade",
+        "[Soul and Shade](Achievements): Complete the [Pantheon of the Knight]",
+        Flag("bossDoorStateTier4.completed"),
+    ),
+    check(
+        "achievement.embrace_the_void",
+        "[Embrace the Void](Achievements): Complete the [Pantheon of Hallownest]",
+        Flag("bossDoorStateTier5.completed"),
+    ),
+];
+
+impl SaveFile {
+    /// Evaluates every check against this save.
+    #[must_use]
+    pub fn checks(&self) -> HollowKnightChecks {
+        self.evaluate(&self.player_data_value())
+    }
+
+    /// [`Self::checks`], with `playerData` already serialized.
+    pub(crate) fn evaluate(&self, player_data: &Value) -> HollowKnightChecks {
+        let index = SceneIndex::new(&self.scene_data.persistent_bool_items);
+        let scene_activated = |name: &str, id: &str| index.activated(name, id);
+
+        let mut checks = Checklist::evaluate(CHECKS, player_data, scene_activated);
+        checks.fill(
+            "geoRocks",
+            "geo_rock",
+            self.scene_data.geo_rocks.iter().map(|rock| {
+                let name = format!("{} {}", rock.scene_name, rock.id);
+                (name.clone(), name, rock.is_broken())
+            }),
+        );
+        let journal = journal(player_data);
+        checks.set(
+            "achievements",
+            "achievement.keen_hunter",
+            !journal.is_empty() && journal.iter().all(|entry| entry.discovered),
+        );
+        checks.fill(
+            "huntersJournal",
+            "journal",
+            journal
+                .into_iter()
+                .map(|entry| (words(&entry.enemy), entry.label(), entry.completed)),
+        );
+        checks
+    }
+
+    /// Every Hunter's Journal entry, sorted by the save's enemy name.
+    ///
+    /// The save has `killed<Enemy>`, `kills<Enemy>` and `newData<Enemy>` for
+    /// every enemy the journal lists, so entries are read from there rather
+    /// than from a list that would need updating with the game.
+    #[must_use]
+    pub fn journal(&self) -> Vec<JournalEntry> {
+        journal(&self.player_data_value())
+    }
+
+    /// Progress towards the achievements that count something, like 34/40
+    /// charms for Blessed.
+    #[must_use]
+    pub fn achievement_progress(&self) -> Vec<AchievementProgress> {
+        self.progress(&self.journal())
+    }
+
+    fn progress(&self, journal: &[JournalEntry]) -> Vec<AchievementProgress> {
+        let data = &self.player_data;
+        let shards = (data.max_health_base - 5) * 4 + data.heart_pieces;
+        let fragments = data.mp_reserve_max / 33 * 3 + data.vessel_fragments;
+        let discovered = journal.iter().filter(|e| e.discovered).count();
+        let discovered = i32::try_from(discovered).unwrap_or(i32::MAX);
+        let completed = journal.iter().filter(|e| e.completed).count();
+        let completed = i32::try_from(completed).unwrap_or(i32::MAX);
+        let entries = i32::try_from(journal.len()).unwrap_or(i32::MAX);
+
+        [
+            ("achievement.charmed", data.charms_owned, 1),
+            ("achievement.enchanted", data.charms_owned, 20),
+            ("achievement.blessed", data.charms_owned, 40),
+            ("achievement.protected", shards, 4),
+            ("achievement.masked", shards, 16),
+            ("achievement.soulful", fragments, 3),
+            ("achievement.worldsoul", fragments, 9),
+            ("achievement.grubfriend", data.grubs_collected, 23),
+            ("achievement.metamorphosis", data.grubs_collected, 46),
+            ("achievement.keen_hunter", discovered, entries),
+            ("achievement.true_hunter", completed, entries),
+        ]
+        .into_iter()
+        .map(|(id, done, total)| AchievementProgress {
+            id,
+            done: done.clamp(0, total),
+            total,
+        })
+        .collect()
+    }
+
+    pub(crate) fn statistics(&self, player_data: &Value) -> Statistics {
+        let journal = journal(player_data);
+        let progress = self.progress(&journal);
+        Statistics::new(&self.scene_data, journal, progress)
+    }
+
+    /// Player data as JSON, with fields the model doesn't know about.
+    fn player_data_value(&self) -> Value {
+        serde_json::to_value(&self.player_data).unwrap_or_default()
+    }
+
+    pub(crate) fn completion(
+        &self,
+        checks: &HollowKnightChecks,
+        player_data: &Value,
+    ) -> Completion {
+        Completion::new(
+            CHECKS,
+            checks,
+            player_data,
+            self.player_data.completion_percentage,
+        )
+    }
+}
+
+/// A Hollow Knight save, as far as this crate understands it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveFile {
+    pub player_data: Box<PlayerData>,
+    pub scene_data: SceneData,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+// Every save since 1.4 has the fields without a default, which is how older
+// saves are told apart. The rest are defaulted so that a save from a patch or
+// mod that lacks one still parses.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerData {
+    /// Game version that last wrote the save, like `1.5.78.11833`.
+    #[serde(default)]
+    pub version: String,
+    #[serde(default, rename = "profileID")]
+    pub profile_id: i32,
+    /// 0 for a normal save, 1 for Steel Soul and 2 for a dead Steel Soul.
+    #[serde(default)]
+    pub permadeath_mode: i32,
+    pub fireball_level: i32,
+    pub quake_level: i32,
+    pub scream_level: i32,
+    pub has_cyclone: bool,
+    pub has_dash_slash: bool,
+    pub has_upward_slash: bool,
+    pub has_dream_nail: bool,
+    pub has_dream_gate: bool,
+    pub dream_nail_upgraded: bool,
+    pub has_dash: bool,
+    pub has_walljump: bool,
+    pub has_super_dash: bool,
+    pub has_shadow_dash: bool,
+    pub has_acid_armour: bool,
+    pub has_double_jump: bool,
+    pub has_lantern: bool,
+    pub has_tram_pass: bool,
+    pub has_slykey: bool,
+    pub gave_slykey: bool,
+    pub has_white_key: bool,
+    pub has_love_key: bool,
+    pub has_kings_brand: bool,
+    #[serde(default)]
+    pub has_city_key: bool,
+    /// Every Hunter's Journal entry completed.
+    #[serde(default)]
+    pub has_hunters_mark: bool,
+    #[serde(default)]
+    pub has_journal: bool,
+    #[serde(default)]
+    pub journal_entries_completed: i32,
+    #[serde(default)]
+    pub journal_notes_completed: i32,
+    #[serde(default)]
+    pub journal_entries_total: i32,
+    pub notch_shroom_ogres: bool,
+    pub notch_fog_canyon: bool,
+    pub got_lurker_key: bool,
+    pub lurien_defeated: bool,
+    pub hegemol_defeated: bool,
+    pub monomon_defeated: bool,
+    pub sly_shell_frag1: bool,
+    pub sly_shell_frag2: bool,
+    pub sly_shell_frag3: bool,
+    pub sly_shell_frag4: bool,
+    pub sly_vessel_frag1: bool,
+    pub sly_vessel_frag2: bool,
+    pub sly_simple_key: bool,
+    pub moth_departed: bool,
+    pub dream_reward1: bool,
+    #[serde(default)]
+    pub dream_reward2: bool,
+    pub dream_reward3: bool,
+    #[serde(default)]
+    pub dream_reward4: bool,
+    pub dream_reward5: bool,
+    pub dream_reward6: bool,
+    pub dream_reward7: bool,
+    #[serde(default)]
+    pub dream_reward8: bool,
+    #[serde(default)]
+    pub dream_reward9: bool,
+    pub salubra_notch1: bool,
+    pub salubra_notch2: bool,
+    pub salubra_notch3: bool,
+    pub salubra_notch4: bool,
+    #[serde(default)]
+    pub salubra_blessing: bool,
+    pub nail_smith_upgrades: i32,
+    #[serde(default)]
+    pub honed_nail: bool,
+    #[serde(default)]
+    pub has_nail_art: bool,
+    #[serde(default)]
+    pub has_all_nail_arts: bool,
+    pub xun_flower_given: bool,
+    #[serde(default)]
+    pub has_xun_flower: bool,
+    #[serde(default)]
+    pub xun_flower_broken: bool,
+    #[serde(default)]
+    pub xun_flower_broke_times: i32,
+    pub colosseum_bronze_completed: bool,
+    pub colosseum_silver_completed: bool,
+    pub colosseum_gold_completed: bool,
+    #[serde(rename = "gotCharm_1")]
+    pub got_charm_1: bool,
+    #[serde(rename = "gotCharm_2")]
+    pub got_charm_2: bool,
+    #[serde(rename = "gotCharm_3")]
+    pub got_charm_3: bool,
+    #[serde(rename = "gotCharm_4")]
+    pub got_charm_4: bool,
+    #[serde(rename = "gotCharm_5")]
+    pub got_charm_5: bool,
+    #[serde(rename = "gotCharm_6")]
+    pub got_charm_6: bool,
+    #[serde(rename = "gotCharm_7")]
+    pub got_charm_7: bool,
+    #[serde(rename = "gotCharm_8")]
+    pub got_charm_8: bool,
+    #[serde(rename = "gotCharm_9")]
+    pub got_charm_9: bool,
+    #[serde(rename = "gotCharm_10")]
+    pub got_charm_10: bool,
+    #[serde(rename = "gotCharm_11")]
+    pub got_charm_11: bool,
+    #[serde(rename = "gotCharm_12")]
+    pub got_charm_12: bool,
+    #[serde(rename = "gotCharm_13")]
+    pub got_charm_13: bool,
+    #[serde(rename = "gotCharm_14")]
+    pub got_charm_14: bool,
+    #[serde(rename = "gotCharm_15")]
+    pub got_charm_15: bool,
+    #[serde(rename = "gotCharm_16")]
+    pub got_charm_16: bool,
+    #[serde(rename = "gotCharm_17")]
+    pub got_charm_17: bool,
+    #[serde(rename = "gotCharm_18")]
+    pub got_charm_18: bool,
+    #[serde(rename = "gotCharm_19")]
+    pub got_charm_19: bool,
+    #[serde(rename = "gotCharm_20")]
+    pub got_charm_20: bool,
+    #[serde(rename = "gotCharm_21")]
+    pub got_charm_21: bool,
+    #[serde(rename = "gotCharm_22")]
+    pub got_charm_22: bool,
+    #[serde(rename = "gotCharm_23")]
+    pub got_charm_23: bool,
+    #[serde(rename = "gotCharm_24")]
+    pub got_charm_24: bool,
+    #[serde(rename = "gotCharm_25")]
+    pub got_charm_25: bool,
+    #[serde(rename = "gotCharm_26")]
+    pub got_charm_26: bool,
+    #[serde(rename = "gotCharm_27")]
+    pub got_charm_27: bool,
+    #[serde(rename = "gotCharm_28")]
+    pub got_charm_28: bool,
+    #[serde(rename = "gotCharm_29")]
+    pub got_charm_29: bool,
+    #[serde(rename = "gotCharm_30")]
+    pub got_charm_30: bool,
+    #[serde(rename = "gotCharm_31")]
+    pub got_charm_31: bool,
+    #[serde(rename = "gotCharm_32")]
+    pub got_charm_32: bool,
+    #[serde(rename = "gotCharm_33")]
+    pub got_charm_33: bool,
+    #[serde(rename = "gotCharm_34")]
+    pub got_charm_34: bool,
+    #[serde(rename = "gotCharm_35")]
+    pub got_charm_35: bool,
+    #[serde(rename = "gotCharm_36")]
+    pub got_charm_36: bool,
+    #[serde(rename = "gotCharm_37")]
+    pub got_charm_37: bool,
+    #[serde(rename = "gotCharm_38")]
+    pub got_charm_38: bool,
+    #[serde(rename = "gotCharm_39")]
+    pub got_charm_39: bool,
+    #[serde(rename = "gotCharm_40")]
+    pub got_charm_40: bool,
+    #[serde(default, rename = "charmCost_1")]
+    pub charm_cost_1: i32,
+    #[serde(default, rename = "charmCost_2")]
+    pub charm_cost_2: i32,
+    #[serde(default, rename = "charmCost_3")]
+    pub charm_cost_3: i32,
+    #[serde(default, rename = "charmCost_4")]
+    pub charm_cost_4: i32,
+    #[serde(default, rename = "charmCost_5")]
+    pub charm_cost_5: i32,
+    #[serde(default, rename = "charmCost_6")]
+    pub charm_cost_6: i32,
+    #[serde(default, rename = "charmCost_7")]
+    pub charm_cost_7: i32,
+    #[serde(default, rename = "charmCost_8")]
+    pub charm_cost_8: i32,
+    #[serde(default, rename = "charmCost_9")]
+    pub charm_cost_9: i32,
+    #[serde(default, rename = "charmCost_10")]
+    pub charm_cost_10: i32,
+    #[serde(default, rename = "charmCost_11")]
+    pub charm_cost_11: i32,
+    #[serde(default, rename = "charmCost_12")]
+    pub charm_cost_12: i32,
+    #[serde(default, rename = "charmCost_13")]
+    pub charm_cost_13: i32,
+    #[serde(default, rename = "charmCost_14")]
+    pub charm_cost_14: i32,
+    #[serde(default, rename = "charmCost_15")]
+    pub charm_cost_15: i32,
+    #[serde(default, rename = "charmCost_16")]
+    pub charm_cost_16: i32,
+    #[serde(default, rename = "charmCost_17")]
+    pub charm_cost_17: i32,
+    #[serde(default, rename = "charmCost_18")]
+    pub charm_cost_18: i32,
+    #[serde(default, rename = "charmCost_19")]
+    pub charm_cost_19: i32,
+    #[serde(default, rename = "charmCost_20")]
+    pub charm_cost_20: i32,
+    #[serde(default, rename = "charmCost_21")]
+    pub charm_cost_21: i32,
+    #[serde(default, rename = "charmCost_22")]
+    pub charm_cost_22: i32,
+    #[serde(default, rename = "charmCost_23")]
+    pub charm_cost_23: i32,
+    #[serde(default, rename = "charmCost_24")]
+    pub charm_cost_24: i32,
+    #[serde(default, rename = "charmCost_25")]
+    pub charm_cost_25: i32,
+    #[serde(default, rename = "charmCost_26")]
+    pub charm_cost_26: i32,
+    #[serde(default, rename = "charmCost_27")]
+    pub charm_cost_27: i32,
+    #[serde(default, rename = "charmCost_28")]
+    pub charm_cost_28: i32,
+    #[serde(default, rename = "charmCost_29")]
+    pub charm_cost_29: i32,
+    #[serde(default, rename = "charmCost_30")]
+    pub charm_cost_30: i32,
+    #[serde(default, rename = "charmCost_31")]
+    pub charm_cost_31: i32,
+    #[serde(default, rename = "charmCost_32")]
+    pub charm_cost_32: i32,
+    #[serde(default, rename = "charmCost_33")]
+    pub charm_cost_33: i32,
+    #[serde(default, rename = "charmCost_34")]
+    pub charm_cost_34: i32,
+    #[serde(default, rename = "charmCost_35")]
+    pub charm_cost_35: i32,
+    #[serde(default, rename = "charmCost_36")]
+    pub charm_cost_36: i32,
+    #[serde(default, rename = "charmCost_37")]
+    pub charm_cost_37: i32,
+    #[serde(default, rename = "charmCost_38")]
+    pub charm_cost_38: i32,
+    #[serde(default, rename = "charmCost_39")]
+    pub charm_cost_39: i32,
+    #[serde(default, rename = "charmCost_40")]
+    pub charm_cost_40: i32,
+    #[serde(default, rename = "equippedCharm_1")]
+    pub equipped_charm_1: bool,
+    #[serde(default, rename = "equippedCharm_2")]
+    pub equipped_charm_2: bool,
+    #[serde(default, rename = "equippedCharm_3")]
+    pub equipped_charm_3: bool,
+    #[serde(default, rename = "equippedCharm_4")]
+    pub equipped_charm_4: bool,
+    #[serde(default, rename = "equippedCharm_5")]
+    pub equipped_charm_5: bool,
+    #[serde(default, rename = "equippedCharm_6")]
+    pub equipped_charm_6: bool,
+    #[serde(default, rename = "equippedCharm_7")]
+    pub equipped_charm_7: bool,
+    #[serde(default, rename = "equippedCharm_8")]
+    pub equipped_charm_8: bool,
+    #[serde(default, rename = "equippedCharm_9")]
+    pub equipped_charm_9: bool,
+    #[serde(default, rename = "equippedCharm_10")]
+    pub equipped_charm_10: bool,
+    #[serde(default, rename = "equippedCharm_11")]
+    pub equipped_charm_11: bool,
+    #[serde(default, rename = "equippedCharm_12")]
+    pub equipped_charm_12: bool,
+    #[serde(default, rename = "equippedCharm_13")]
+    pub equipped_charm_13: bool,
+    #[serde(default, rename = "equippedCharm_14")]
+    pub equipped_charm_14: bool,
+    #[serde(default, rename = "equippedCharm_15")]
+    pub equipped_charm_15: bool,
+    #[serde(default, rename = "equippedCharm_16")]
+    pub equipped_charm_16: bool,
+    #[serde(default, rename = "equippedCharm_17")]
+    pub equipped_charm_17: bool,
+    #[serde(default, rename = "equippedCharm_18")]
+    pub equipped_charm_18: bool,
+    #[serde(default, rename = "equippedCharm_19")]
+    pub equipped_charm_19: bool,
+    #[serde(default, rename = "equippedCharm_20")]
+    pub equipped_charm_20: bool,
+    #[serde(default, rename = "equippedCharm_21")]
+    pub equipped_charm_21: bool,
+    #[serde(default, rename = "equippedCharm_22")]
+    pub equipped_charm_22: bool,
+    #[serde(default, rename = "equippedCharm_23")]
+    pub equipped_charm_23: bool,
+    #[serde(default, rename = "equippedCharm_24")]
+    pub equipped_charm_24: bool,
+    #[serde(default, rename = "equippedCharm_25")]
+    pub equipped_charm_25: bool,
+    #[serde(default, rename = "equippedCharm_26")]
+    pub equipped_charm_26: bool,
+    #[serde(default, rename = "equippedCharm_27")]
+    pub equipped_charm_27: bool,
+    #[serde(default, rename = "equippedCharm_28")]
+    pub equipped_charm_28: bool,
+    #[serde(default, rename = "equippedCharm_29")]
+    pub equipped_charm_29: bool,
+    #[serde(default, rename = "equippedCharm_30")]
+    pub equipped_charm_30: bool,
+    #[serde(default, rename = "equippedCharm_31")]
+    pub equipped_charm_31: bool,
+    #[serde(default, rename = "equippedCharm_32")]
+    pub equipped_charm_32: bool,
+    #[serde(default, rename = "equippedCharm_33")]
+    pub equipped_charm_33: bool,
+    #[serde(default, rename = "equippedCharm_34")]
+    pub equipped_charm_34: bool,
+    #[serde(default, rename = "equippedCharm_35")]
+    pub equipped_charm_35: bool,
+    #[serde(default, rename = "equippedCharm_36")]
+    pub equipped_charm_36: bool,
+    #[serde(default, rename = "equippedCharm_37")]
+    pub equipped_charm_37: bool,
+    #[serde(default, rename = "equippedCharm_38")]
+    pub equipped_charm_38: bool,
+    #[serde(default, rename = "equippedCharm_39")]
+    pub equipped_charm_39: bool,
+    #[serde(default, rename = "equippedCharm_40")]
+    pub equipped_charm_40: bool,
+    #[serde(default, rename = "newCharm_1")]
+    pub new_charm_1: bool,
+    #[serde(default, rename = "newCharm_2")]
+    pub new_charm_2: bool,
+    #[serde(default, rename = "newCharm_3")]
+    pub new_charm_3: bool,
+    #[serde(default, rename = "newCharm_4")]
+    pub new_charm_4: bool,
+    #[serde(default, rename = "newCharm_5")]
+    pub new_charm_5: bool,
+    #[serde(default, rename = "newCharm_6")]
+    pub new_charm_6: bool,
+    #[serde(default, rename = "newCharm_7")]
+    pub new_charm_7: bool,
+    #[serde(default, rename = "newCharm_8")]
+    pub new_charm_8: bool,
+    #[serde(default, rename = "newCharm_9")]
+    pub new_charm_9: bool,
+    #[serde(default, rename = "newCharm_10")]
+    pub new_charm_10: bool,
+    #[serde(default, rename = "newCharm_11")]
+    pub new_charm_11: bool,
+    #[serde(default, rename = "newCharm_12")]
+    pub new_charm_12: bool,
+    #[serde(default, rename = "newCharm_13")]
+    pub new_charm_13: bool,
+    #[serde(default, rename = "newCharm_14")]
+    pub new_charm_14: bool,
+    #[serde(default, rename = "newCharm_15")]
+    pub new_charm_15: bool,
+    #[serde(default, rename = "newCharm_16")]
+    pub new_charm_16: bool,
+    #[serde(default, rename = "newCharm_17")]
+    pub new_charm_17: bool,
+    #[serde(default, rename = "newCharm_18")]
+    pub new_charm_18: bool,
+    #[serde(default, rename = "newCharm_19")]
+    pub new_charm_19: bool,
+    #[serde(default, rename = "newCharm_20")]
+    pub new_charm_20: bool,
+    #[serde(default, rename = "newCharm_21")]
+    pub new_charm_21: bool,
+    #[serde(default, rename = "newCharm_22")]
+    pub new_charm_22: bool,
+    #[serde(default, rename = "newCharm_23")]
+    pub new_charm_23: bool,
+    #[serde(default, rename = "newCharm_24")]
+    pub new_charm_24: bool,
+    #[serde(default, rename = "newCharm_25")]
+    pub new_charm_25: bool,
+    #[serde(default, rename = "newCharm_26")]
+    pub new_charm_26: bool,
+    #[serde(default, rename = "newCharm_27")]
+    pub new_charm_27: bool,
+    #[serde(default, rename = "newCharm_28")]
+    pub new_charm_28: bool,
+    #[serde(default, rename = "newCharm_29")]
+    pub new_charm_29: bool,
+    #[serde(default, rename = "newCharm_30")]
+    pub new_charm_30: bool,
+    #[serde(default, rename = "newCharm_31")]
+    pub new_charm_31: bool,
+    #[serde(default, rename = "newCharm_32")]
+    pub new_charm_32: bool,
+    #[serde(default, rename = "newCharm_33")]
+    pub new_charm_33: bool,
+    #[serde(default, rename = "newCharm_34")]
+    pub new_charm_34: bool,
+    #[serde(default, rename = "newCharm_35")]
+    pub new_charm_35: bool,
+    #[serde(default, rename = "newCharm_36")]
+    pub new_charm_36: bool,
+    #[serde(default, rename = "newCharm_37")]
+    pub new_charm_37: bool,
+    #[serde(default, rename = "newCharm_38")]
+    pub new_charm_38: bool,
+    #[serde(default, rename = "newCharm_39")]
+    pub new_charm_39: bool,
+    #[serde(default, rename = "newCharm_40")]
+    pub new_charm_40: bool,
+    #[serde(default)]
+    pub can_overcharm: bool,
+    #[serde(default)]
+    pub overcharmed: bool,
+    /// 1 to 4 as Grimmchild grows, 5 once it is Carefree Melody.
+    #[serde(default)]
+    pub grimm_child_level: i32,
+    /// 1 and 2 for the White Fragment halves, 3 for Kingsoul and 4 for the
+    /// Void Heart.
+    #[serde(default)]
+    pub royal_charm_state: i32,
+    #[serde(default, rename = "fragileHealth_unbreakable")]
+    pub fragile_health_unbreakable: bool,
+    #[serde(default, rename = "fragileGreed_unbreakable")]
+    pub fragile_greed_unbreakable: bool,
+    #[serde(default, rename = "fragileStrength_unbreakable")]
+    pub fragile_strength_unbreakable: bool,
+    #[serde(default, rename = "brokenCharm_23")]
+    pub broken_charm_23: bool,
+    #[serde(default, rename = "brokenCharm_24")]
+    pub broken_charm_24: bool,
+    #[serde(default, rename = "brokenCharm_25")]
+    pub broken_charm_25: bool,
+    pub got_king_fragment: bool,
+    pub got_queen_fragment: bool,
+    /// The Void Heart, which replaces Kingsoul.
+    #[serde(default)]
+    pub got_shade_charm: bool,
+    pub killed_big_buzzer: bool,
+    pub killed_big_fly: bool,
+    pub killed_mawlek: bool,
+    pub killed_mega_moss_charger: bool,
+    pub killed_infected_knight: bool,
+    pub killed_black_knight: bool,
+    pub killed_mage_knight: bool,
+    pub killed_jar_collector: bool,
+    pub killed_flukeman: bool,
+    pub killed_mega_beam_miner: bool,
+    pub killed_mimic_spider: bool,
+    pub killed_hive_knight: bool,
+    pub killed_traitor_lord: bool,
+    pub killed_oblobble: bool,
+    pub killed_lobster_lancer: bool,
+    pub killed_hollow_knight: bool,
+    pub killed_final_boss: bool,
+    pub killed_grimm: bool,
+    pub killed_nightmare_grimm: bool,
+    /// Set by banishing the troupe instead of fighting Nightmare King Grimm.
+    #[serde(default)]
+    pub destroyed_nightmare_lantern: bool,
+    pub killed_nail_bros: bool,
+    pub killed_paintmaster: bool,
+    pub killed_nailsage: bool,
+    pub killed_hollow_knight_prime: bool,
+    pub false_knight_defeated: bool,
+    pub false_knight_dream_defeated: bool,
+    pub hornet1_defeated: bool,
+    pub hornet_outskirts_defeated: bool,
+    pub mage_lord_dream_defeated: bool,
+    pub infected_knight_dream_defeated: bool,
+    pub white_defender_defeated: bool,
+    pub grey_prince_defeated: bool,
+    #[serde(default)]
+    pub zote_rescued_buzzer: bool,
+    #[serde(default)]
+    pub zote_dead: bool,
+    #[serde(default)]
+    pub zote_defeated: bool,
+    pub aladar_slug_defeated: i32,
+    pub xero_defeated: i32,
+    pub elder_hu_defeated: i32,
+    pub mum_caterpillar_defeated: i32,
+    pub no_eyes_defeated: i32,
+    pub markoth_defeated: i32,
+    pub galien_defeated: i32,
+    pub vessel_frag_stag_nest: bool,
+    pub defeated_mega_jelly: bool,
+    pub defeated_mantis_lords: bool,
+    pub mage_lord_defeated: bool,
+    pub opened_love_door: bool,
+    pub defeated_dung_defender: bool,
+    pub got_grimm_notch: bool,
+    pub boss_door_state_tier1: BossDoorStateTier,
+    pub boss_door_state_tier2: BossDoorStateTier,
+    pub boss_door_state_tier3: BossDoorStateTier,
+    pub boss_door_state_tier4: BossDoorStateTier,
+    pub boss_door_state_tier5: BossDoorStateTier,
+    pub has_godfinder: bool,
+    #[serde(default)]
+    pub godseeker_unlocked: bool,
+    /// Started from the Godseeker mode menu.
+    #[serde(default)]
+    pub boss_rush_mode: bool,
+    pub nymm_in_town: bool,
+    #[serde(default)]
+    pub troupe_in_town: bool,
+    #[serde(default)]
+    pub divine_in_town: bool,
+    #[serde(default)]
+    pub nightmare_lantern_appeared: bool,
+    #[serde(default)]
+    pub nightmare_lantern_lit: bool,
+    /// Grimmkin flames carried, up to `flames_required`.
+    #[serde(default)]
+    pub flames_collected: i32,
+    #[serde(default)]
+    pub flames_required: i32,
+    #[serde(default)]
+    pub completion_percentage: Number,
+    #[serde(default)]
+    pub unlocked_completion_rate: bool,
+    #[serde(default)]
+    pub geo: i32,
+    /// Geo left with the shade, lost on the next death.
+    #[serde(default)]
+    pub geo_pool: i32,
+    #[serde(default)]
+    pub banker_account_purchased: bool,
+    /// Geo deposited with Millibelle.
+    #[serde(default)]
+    pub banker_balance: i32,
+    /// Scene the shade is in, if there is one.
+    #[serde(default)]
+    pub shade_scene: String,
+    #[serde(default)]
+    pub health: i32,
+    #[serde(default)]
+    pub max_health: i32,
+    /// Max health without the Lifeblood and Fragile/Unbreakable Heart
+    /// bonuses.
+    #[serde(default)]
+    pub max_health_base: i32,
+    #[serde(default)]
+    pub prev_health: i32,
+    #[serde(default)]
+    pub damaged_blue: bool,
+    /// Soul in the main vessel.
+    #[serde(default, rename = "MPCharge")]
+    pub mp_charge: i32,
+    #[serde(default, rename = "maxMP")]
+    pub max_mp: i32,
+    /// Soul in the vessel fragments.
+    #[serde(default, rename = "MPReserve")]
+    pub mp_reserve: i32,
+    #[serde(default, rename = "MPReserveMax")]
+    pub mp_reserve_max: i32,
+    #[serde(default, rename = "MPReserveCap")]
+    pub mp_reserve_cap: i32,
+    /// Soul a heal costs.
+    #[serde(default, rename = "focusMP_amount")]
+    pub focus_mp_amount: i32,
+    /// The shade is out there, capping soul at two thirds.
+    #[serde(default)]
+    pub soul_limited: bool,
+    /// Charm notches.
+    #[serde(default)]
+    pub charm_slots: i32,
+    #[serde(default)]
+    pub charm_slots_filled: i32,
+    /// Ids of the equipped charms, matching the `gotCharm_<id>` flags.
+    #[serde(default)]
+    pub equipped_charms: Vec<i32>,
+    #[serde(default)]
+    pub nail_damage: i32,
+    #[serde(default)]
+    pub beam_damage: i32,
+    /// In seconds.
+    #[serde(default)]
+    pub play_time: Number,
+    /// Scene the player respawns in, i.e. where the game was saved.
+    #[serde(default)]
+    pub respawn_scene: String,
+    /// Bench or other spawn point in `respawn_scene`.
+    #[serde(default)]
+    pub respawn_marker_name: String,
+    #[serde(default)]
+    pub respawn_type: i32,
+    #[serde(default)]
+    pub respawn_facing_right: bool,
+    #[serde(default)]
+    pub at_bench: bool,
+    /// Map area the player is in, as the game's `MapZone` number.
+    #[serde(default)]
+    pub map_zone: i32,
+    #[serde(default)]
+    pub hazard_respawn_location: Vector3,
+    #[serde(default)]
+    pub hazard_respawn_facing_right: bool,
+    /// Map area the shade is in.
+    #[serde(default)]
+    pub shade_map_zone: String,
+    #[serde(default)]
+    pub shade_position_x: Number,
+    #[serde(default)]
+    pub shade_position_y: Number,
+    #[serde(default)]
+    pub shade_health: i32,
+    #[serde(default, rename = "shadeMP")]
+    pub shade_mp: i32,
+    #[serde(default)]
+    pub shade_fireball_level: i32,
+    #[serde(default)]
+    pub shade_quake_level: i32,
+    #[serde(default)]
+    pub shade_scream_level: i32,
+    #[serde(default)]
+    pub shade_special_type: i32,
+    /// Lifeblood masks.
+    #[serde(default)]
+    pub health_blue: i32,
+    #[serde(default)]
+    pub joni_health_blue: i32,
+    /// Mask shards towards the next mask.
+    #[serde(default)]
+    pub heart_pieces: i32,
+    #[serde(default)]
+    pub heart_piece_collected: bool,
+    #[serde(default)]
+    pub heart_piece_max: bool,
+    /// Vessel fragments towards the next soul vessel.
+    #[serde(default)]
+    pub vessel_fragments: i32,
+    #[serde(default)]
+    pub vessel_fragment_collected: bool,
+    #[serde(default)]
+    pub vessel_fragment_max: bool,
+    #[serde(default)]
+    pub charms_owned: i32,
+    #[serde(default)]
+    pub grubs_collected: i32,
+    #[serde(default)]
+    pub simple_keys: i32,
+    /// Pale ore.
+    #[serde(default)]
+    pub ore: i32,
+    #[serde(default)]
+    pub rancid_eggs: i32,
+    /// Wanderer's Journals carried.
+    #[serde(default)]
+    pub trinket1: i32,
+    /// Hallownest Seals carried.
+    #[serde(default)]
+    pub trinket2: i32,
+    /// King's Idols carried.
+    #[serde(default)]
+    pub trinket3: i32,
+    /// Arcane Eggs carried.
+    #[serde(default)]
+    pub trinket4: i32,
+    #[serde(default)]
+    pub sold_trinket1: i32,
+    #[serde(default)]
+    pub sold_trinket2: i32,
+    #[serde(default)]
+    pub sold_trinket3: i32,
+    #[serde(default)]
+    pub sold_trinket4: i32,
+    #[serde(default)]
+    pub jinn_eggs_sold: i32,
+    /// Essence.
+    #[serde(default)]
+    pub dream_orbs: i32,
+    #[serde(default)]
+    pub dream_orbs_spent: i32,
+    /// Where the Dreamgate is set.
+    #[serde(default)]
+    pub dream_gate_scene: String,
+    #[serde(default)]
+    pub dream_gate_x: Number,
+    #[serde(default)]
+    pub dream_gate_y: Number,
+    #[serde(default)]
+    pub environment_type: i32,
+    #[serde(default)]
+    pub environment_type_default: i32,
+    #[serde(default)]
+    pub previous_darkness: i32,
+    #[serde(default)]
+    pub opening_credits_played: bool,
+    #[serde(default)]
+    pub visited_dirtmouth: bool,
+    #[serde(default)]
+    pub visited_crossroads: bool,
+    #[serde(default)]
+    pub visited_greenpath: bool,
+    #[serde(default)]
+    pub visited_fungus: bool,
+    #[serde(default)]
+    pub visited_hive: bool,
+    #[serde(default)]
+    pub visited_ruins: bool,
+    #[serde(default)]
+    pub visited_mines: bool,
+    #[serde(default)]
+    pub visited_royal_gardens: bool,
+    #[serde(default)]
+    pub visited_fog_canyon: bool,
+    #[serde(default)]
+    pub visited_deepnest: bool,
+    #[serde(default)]
+    pub visited_resting_grounds: bool,
+    #[serde(default)]
+    pub visited_waterways: bool,
+    #[serde(default)]
+    pub visited_abyss: bool,
+    #[serde(default)]
+    pub visited_outskirts: bool,
+    #[serde(default)]
+    pub visited_white_palace: bool,
+    #[serde(default)]
+    pub visited_cliffs: bool,
+    #[serde(default)]
+    pub visited_godhome: bool,
+    #[serde(default)]
+    pub crossroads_infected: bool,
+    /// Stag stations, `opened_ruins1` is the City Storerooms one.
+    #[serde(default)]
+    pub opened_crossroads: bool,
+    #[serde(default)]
+    pub opened_greenpath: bool,
+    #[serde(default)]
+    pub opened_ruins1: bool,
+    #[serde(default)]
+    pub opened_ruins2: bool,
+    #[serde(default)]
+    pub opened_fungal_wastes: bool,
+    #[serde(default)]
+    pub opened_royal_gardens: bool,
+    #[serde(default)]
+    pub opened_resting_grounds: bool,
+    #[serde(default)]
+    pub opened_deepnest: bool,
+    #[serde(default)]
+    pub opened_stag_nest: bool,
+    #[serde(default)]
+    pub opened_hidden_station: bool,
+    #[serde(default)]
+    pub stag_position: i32,
+    #[serde(default)]
+    pub stations_opened: i32,
+    #[serde(default)]
+    pub opened_tram_lower: bool,
+    #[serde(default)]
+    pub opened_tram_resting_grounds: bool,
+    #[serde(default)]
+    pub tram_lower_position: i32,
+    #[serde(default)]
+    pub tram_resting_grounds_position: i32,
+    #[serde(default)]
+    pub mine_lift_opened: bool,
+    #[serde(default)]
+    pub opened_city_gate: bool,
+    #[serde(default)]
+    pub opened_waterways_manhole: bool,
+    #[serde(default)]
+    pub has_map: bool,
+    #[serde(default)]
+    pub has_quill: bool,
+    #[serde(default)]
+    pub map_all_rooms: bool,
+    #[serde(default)]
+    pub map_dirtmouth: bool,
+    #[serde(default)]
+    pub map_crossroads: bool,
+    #[serde(default)]
+    pub map_greenpath: bool,
+    #[serde(default)]
+    pub map_fog_canyon: bool,
+    #[serde(default)]
+    pub map_royal_gardens: bool,
+    #[serde(default)]
+    pub map_fungal_wastes: bool,
+    #[serde(default)]
+    pub map_city: bool,
+    #[serde(default)]
+    pub map_waterways: bool,
+    #[serde(default)]
+    pub map_mines: bool,
+    #[serde(default)]
+    pub map_deepnest: bool,
+    #[serde(default)]
+    pub map_cliffs: bool,
+    #[serde(default)]
+    pub map_outskirts: bool,
+    #[serde(default)]
+    pub map_resting_grounds: bool,
+    #[serde(default)]
+    pub map_abyss: bool,
+    #[serde(default)]
+    pub cornifer_at_home: bool,
+    #[serde(default)]
+    pub has_pin: bool,
+    #[serde(default)]
+    pub has_pin_bench: bool,
+    #[serde(default)]
+    pub has_pin_cocoon: bool,
+    #[serde(default)]
+    pub has_pin_dream_plant: bool,
+    #[serde(default)]
+    pub has_pin_guardian: bool,
+    #[serde(default)]
+    pub has_pin_black_egg: bool,
+    #[serde(default)]
+    pub has_pin_shop: bool,
+    #[serde(default)]
+    pub has_pin_spa: bool,
+    #[serde(default)]
+    pub has_pin_stag: bool,
+    #[serde(default)]
+    pub has_pin_tram: bool,
+    #[serde(default)]
+    pub has_pin_ghost: bool,
+    #[serde(default)]
+    pub has_pin_grub: bool,
+    #[serde(default)]
+    pub has_marker: bool,
+    #[serde(default, rename = "hasMarker_r")]
+    pub has_marker_r: bool,
+    #[serde(default, rename = "hasMarker_b")]
+    pub has_marker_b: bool,
+    #[serde(default, rename = "hasMarker_y")]
+    pub has_marker_y: bool,
+    #[serde(default, rename = "hasMarker_w")]
+    pub has_marker_w: bool,
+    #[serde(default, rename = "spareMarkers_r")]
+    pub spare_markers_r: i32,
+    #[serde(default, rename = "spareMarkers_b")]
+    pub spare_markers_b: i32,
+    #[serde(default, rename = "spareMarkers_y")]
+    pub spare_markers_y: i32,
+    #[serde(default, rename = "spareMarkers_w")]
+    pub spare_markers_w: i32,
+    /// Map markers, by colour.
+    #[serde(default, rename = "placedMarkers_r")]
+    pub placed_markers_r: Vec<Vector3>,
+    #[serde(default, rename = "placedMarkers_b")]
+    pub placed_markers_b: Vec<Vector3>,
+    #[serde(default, rename = "placedMarkers_y")]
+    pub placed_markers_y: Vec<Vector3>,
+    #[serde(default, rename = "placedMarkers_w")]
+    pub placed_markers_w: Vec<Vector3>,
+    #[serde(default)]
+    pub scenes_visited: Vec<String>,
+    #[serde(default)]
+    pub scenes_mapped: Vec<String>,
+    #[serde(default)]
+    pub scenes_encountered_bench: Vec<String>,
+    #[serde(default)]
+    pub scenes_grub_rescued: Vec<String>,
+    #[serde(default)]
+    pub scenes_flame_collected: Vec<String>,
+    #[serde(default)]
+    pub scenes_encountered_cocoon: Vec<String>,
+    #[serde(default)]
+    pub scenes_encountered_dream_plant: Vec<String>,
+    #[serde(default)]
+    pub scenes_encountered_dream_plant_c: Vec<String>,
+    /// Every key this model doesn't know about, kept so that re-encoding
+    /// loses nothing. That is mostly the journal's `killed<Enemy>`,
+    /// `kills<Enemy>` and `newData<Enemy>` counters, see
+    /// [`SaveFile::journal`], and NPC dialogue flags.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BossDoorStateTier {
+    pub completed: bool,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneData {
+    pub persistent_bool_items: Vec<SceneObjectBool>,
+    /// Objects that take several hits or have several states, like
+    /// breakable walls.
+    pub persistent_int_items: Vec<SceneObjectInt>,
+    pub geo_rocks: Vec<GeoRock>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl SceneData {
+    /// State of the `(sceneName, id)` object in `persistentIntItems`.
+    #[must_use]
+    pub fn int_item(&self, scene_name: &str, id: &str) -> Option<i32> {
+        self.persistent_int_items
+            .iter()
+            .find(|x| x.scene_name == scene_name && x.id == id)
+            .map(|x| x.value)
+    }
+
+    /// Geo rocks the save knows about in the given area, see [`scene_area`].
+    pub fn geo_rocks_in<'a>(&'a self, area: &'a str) -> impl Iterator<Item = &'a GeoRock> {
+        self.geo_rocks
+            .iter()
+            .filter(move |rock| scene_area(&rock.scene_name) == area)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneObjectBool {
+    pub id: String,
+    pub scene_name: String,
+    pub activated: bool,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// `persistentBoolItems` by scene, so that checks don't each scan the
+/// thousands of entries of a late-game save.
+///
+/// The game appends a scene's objects together whenever the player leaves
+/// it, so the index keeps runs of entries rather than hashing every one.
+struct SceneIndex<'a> {
+    scenes: HashMap<&'a str, Vec<&'a [SceneObjectBool]>>,
+}
+
+impl<'a> SceneIndex<'a> {
+    fn new(items: &'a [SceneObjectBool]) -> Self {
+        let mut scenes: HashMap<_, Vec<_>> = HashMap::new();
+        for run in items.chunk_by(|a, b| a.scene_name == b.scene_name) {
+            scenes
+                .entry(run[0].scene_name.as_str())
+                .or_default()
+                .push(run);
+        }
+        Self { scenes }
+    }
+
+    /// Whether `(scene_name, id)` is activated. Like the game, the first
+    /// entry wins if there are duplicates.
+    fn activated(&self, scene_name: &str, id: &str) -> bool {
+        self.scenes
+            .get(scene_name)
+            .into_iter()
+            .flatten()
+            .flat_map(|run| run.iter())
+            .find(|x| x.id == id)
+            .is_some_and(|x| x.activated)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneObjectInt {
+    pub id: String,
+    pub scene_name: String,
+    pub value: i32,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GeoRock {
+    pub id: String,
+    pub scene_name: String,
+    /// Hits until the rock breaks, every hit drops some geo.
+    pub hits_left: i32,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl GeoRock {
+    #[must_use]
+    pub fn is_broken(&self) -> bool {
+        self.hits_left <= 0
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct Vector3 {
+    pub x: Number,
+    pub y: Number,
+    pub z: Number,
+}
+
+/// A Hunter's Journal entry.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    /// Enemy name the save uses, like `ZombieRunner` for Wandering Husk.
+    pub enemy: String,
+    /// The enemy has been killed at least once, so it's in the journal.
+    pub discovered: bool,
+    /// Enough have been killed for the Hunter's notes, which the Hunter's
+    /// Mark needs for every entry.
+    pub completed: bool,
+    /// Kills left until the entry is completed.
+    pub kills_left: i32,
+}
+
+impl JournalEntry {
+    /// The journal's name for the enemy, `ZombieRunner` -> `Wandering Husk`.
+    /// Enemies the table doesn't have get their save name split into
+    /// words, `FalseKnight` -> `False Knight`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        match JOURNAL_NAMES.iter().find(|(enemy, _)| *enemy == self.enemy) {
+            Some((_, name)) => (*name).to_owned(),
+            None => words(&self.enemy),
+        }
+    }
+}
+
+/// `FalseKnight` -> `False Knight`.
+fn words(name: &str) -> String {
+    let mut words = String::new();
+    for (i, c) in name.char_indices() {
+        if i > 0 && c.is_ascii_uppercase() {
+            words.push(' ');
+        }
+        words.push(c);
+    }
+    words
+}
+
+/// `(save name, journal name)` of enemies the journal doesn't call by their
+/// save name split into words.
+const JOURNAL_NAMES: &[(&str, &str)] = &[
+    ("Crawler", "Crawlid"),
+    ("Buzzer", "Vengefly"),
+    ("AngryBuzzer", "Furious Vengefly"),
+    ("BigBuzzer", "Vengefly King"),
+    ("Bouncer", "Gruzzer"),
+    ("BurstingBouncer", "Volatile Gruzzer"),
+    ("BigFly", "Gruz Mother"),
+    ("Climber", "Tiktik"),
+    ("Worm", "Goam"),
+    ("Spitter", "Aspid Hunter"),
+    ("SuperSpitter", "Primal Aspid"),
+    ("Hatcher", "Aspid Mother"),
+    ("Hatchling", "Aspid Hatchling"),
+    ("ZombieRunner", "Wandering Husk"),
+    ("ZombieHornhead", "Husk Hornhead"),
+    ("ZombieLeaper", "Leaping Husk"),
+    ("ZombieBarger", "Husk Bully"),
+    ("ZombieShield", "Husk Warrior"),
+    ("ZombieGuard", "Husk Guard"),
+    ("BurstingZombie", "Violent Husk"),
+    ("Mawlek", "Brooding Mawlek"),
+    ("MawlekTurret", "Mawlurk"),
+    ("Roller", "Baldur"),
+    ("Blocker", "Elder Baldur"),
+    ("PrayerSlug", "Maggot"),
+    ("MenderBug", "Menderbug"),
+    ("HealthScuttler", "Lifeseed"),
+    ("MossmanRunner", "Mosskin"),
+    ("MossmanShaker", "Volatile Mosskin"),
+    ("MossWalker", "Mosscreep"),
+    ("MossFlyer", "Mossfly"),
+    ("MossKnightFat", "Mossy Vagabond"),
+    ("MegaMossCharger", "Massive Moss Charger"),
+    ("Mosquito", "Squit"),
+    ("BlobFlyer", "Obble"),
+    ("LazyFlyer", "Aluba"),
+    ("PlantShooter", "Gulka"),
+    ("SnapperTrap", "Fool Eater"),
+    ("GrassHopper", "Loodle"),
+    ("AcidFlyer", "Durandoo"),
+    ("AcidWalker", "Duranda"),
+    ("InfectedKnight", "Broken Vessel"),
+    ("FungifiedZombie", "Fungified Husk"),
+    ("MushroomBaby", "Shrumeling"),
+    ("MushroomRoller", "Shrumal Warrior"),
+    ("MushroomBrawler", "Shrumal Ogre"),
+    ("MushroomTurret", "Sporg"),
+    ("Mantis", "Mantis Warrior"),
+    ("MantisFlyerChild", "Mantis Youth"),
+    ("ZapBug", "Charged Lumafly"),
+    ("Jellyfish", "Ooma"),
+    ("MegaJellyfish", "Uumuu"),
+    ("RuinsSentry", "Husk Sentry"),
+    ("RuinsSentryFat", "Heavy Sentry"),
+    ("FlyingSentrySword", "Winged Sentry"),
+    ("FlyingSentryJavelin", "Lance Sentry"),
+    ("GreatShieldZombie", "Great Husk Sentry"),
+    ("RoyalDandy", "Husk Dandy"),
+    ("RoyalCoward", "Cowardly Husk"),
+    ("RoyalPlumper", "Gluttonous Husk"),
+    ("Mummy", "Entombed Husk"),
+    ("MageKnight", "Soul Warrior"),
+    ("Mage", "Soul Twister"),
+    ("ElectricMage", "Volt Twister"),
+    ("MageBalloon", "Folly"),
+    ("MageBlob", "Mistake"),
+    ("MageLord", "Soul Master"),
+    ("BlackKnight", "Watcher Knight"),
+    ("JarCollector", "The Collector"),
+    ("Inflater", "Hwurmp"),
+    ("FlipHopper", "Pilflip"),
+    ("CeilingDropper", "Belfly"),
+    ("FlukeMother", "Flukemarm"),
+    ("CrystalCrawler", "Glimback"),
+    ("CrystalFlyer", "Crystal Hunter"),
+    ("MinesCrawler", "Shardmite"),
+    ("ZombieMiner", "Husk Miner"),
+    ("ZombieBeamMiner", "Crystallised Husk"),
+    ("MegaBeamMiner", "Crystal Guardian"),
+    ("Centipede", "Garpede"),
+    ("SpiderCorpse", "Corpse Creeper"),
+    ("SlashSpider", "Stalking Devout"),
+    ("SpiderFlyer", "Little Weaver"),
+    ("MimicSpider", "Nosk"),
+    ("ZombieHive", "Husk Hive"),
+    ("BeeHatchling", "Hiveling"),
+    ("BeeStinger", "Hive Soldier"),
+    ("BigBee", "Hive Guardian"),
+    ("GiantHopper", "Great Hopper"),
+    ("BlowFly", "Boofly"),
+    ("AbyssCrawler", "Shadow Creeper"),
+    ("RoyalGuard", "Kingsmould"),
+    ("ColMosquito", "Armoured Squit"),
+    ("ColRoller", "Sharp Baldur"),
+    ("ColShield", "Shielded Fool"),
+    ("ColWorm", "Heavy Fool"),
+    ("ColFlyingSentry", "Winged Fool"),
+    ("ColMiner", "Sturdy Fool"),
+    ("LobsterLancer", "God Tamer"),
+    ("GhostAladar", "Gorb"),
+    ("GhostXero", "Xero"),
+    ("GhostHu", "Elder Hu"),
+    ("GhostMarmu", "Marmu"),
+    ("GhostNoEyes", "No Eyes"),
+    ("GhostMarkoth", "Markoth"),
+    ("GhostGalien", "Galien"),
+    ("Grimm", "Troupe Master Grimm"),
+    ("NightmareGrimm", "Nightmare King Grimm"),
+    ("FlameBearerSmall", "Grimmkin Novice"),
+    ("FlameBearerMed", "Grimmkin Master"),
+    ("FlameBearerLarge", "Grimmkin Nightmare"),
+    ("GreyPrince", "Grey Prince Zote"),
+    ("NailBros", "Brothers Oro & Mato"),
+    ("Paintmaster", "Paintmaster Sheo"),
+    ("Nailsage", "Great Nailsage Sly"),
+    ("HollowKnightPrime", "Pure Vessel"),
+    ("FinalBoss", "The Radiance"),
+];
+
+fn journal(player_data: &Value) -> Vec<JournalEntry> {
+    let Some(fields) = player_data.as_object() else {
+        return Vec::new();
+    };
+    fields
+        .iter()
+        .filter_map(|(key, kills)| {
+            let enemy = key.strip_prefix("kills")?;
+            // `killsX` alone is just a counter, entries have all three
+            fields.get(&format!("newData{enemy}"))?.as_bool()?;
+            let discovered = fields.get(&format!("killed{enemy}"))?.as_bool()?;
+            let kills_left = i32::try_from(kills.as_i64()?).ok()?;
+            Some(JournalEntry {
+                enemy: enemy.to_owned(),
+                discovered,
+                completed: discovered && kills_left <= 0,
+                kills_left: kills_left.max(0),
+            })
+        })
+        .collect()
+}
+
+/// Scene name prefixes of every area, most specific first.
+const AREAS: &[(&str, &str)] = &[
+    ("Tutorial_", "King's Pass"),
+    ("Town", "Dirtmouth"),
+    ("Crossroads_", "Forgotten Crossroads"),
+    ("Fungus1_", "Greenpath"),
+    ("Fungus2_", "Fungal Wastes"),
+    // Fog Canyon and Queen's Gardens share the prefix
+    ("Fungus3_", "Fog Canyon / Queen's Gardens"),
+    ("Ruins", "City of Tears"),
+    ("Mines_", "Crystal Peak"),
+    ("RestingGrounds_", "Resting Grounds"),
+    ("Waterways_", "Royal Waterways"),
+    ("Deepnest_East_", "Kingdom's Edge"),
+    ("Room_Colosseum", "Kingdom's Edge"),
+    ("Deepnest_", "Deepnest"),
+    ("Abyss_", "Ancient Basin"),
+    ("Hive_", "The Hive"),
+    ("Cliffs_", "Howling Cliffs"),
+    ("White_Palace_", "White Palace"),
+    ("GG_", "Godhome"),
+];
+
+/// Map area a scene belongs to, `Other` for shops, dreams and such.
+#[must_use]
+pub fn scene_area(scene_name: &str) -> &'static str {
+    AREAS
+        .iter()
+        .find(|(prefix, _)| scene_name.starts_with(prefix))
+        .map_or("Other", |(_, area)| area)
+}
@@ -1,22 +1,955 @@
-use std::{env, fs};
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::{
+        Arc, Mutex, MutexGuard,
+        mpsc::{self, Sender, TryRecvError},
+    },
+    thread,
+    time::{Duration, SystemTime},
+};
 
-use hollow_knight_save_parser::Parser;
+use clap::{ArgGroup, Parser as _, Subcommand, ValueEnum};
+use hollow_knight_save_parser::{
+    CheckChange, Checklist, Completion, GameSer, JournalEntry, Parser, SaveData, SaveDiff,
+    SaveEditor, Statistics, decrypt_to_json, diff_saves, encode_save_file, json_schema, typescript,
+};
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::{Value, json};
+use tungstenite::{Message, WebSocket, handshake::derive_accept_key, protocol::Role};
 
-fn main() {
-    let Some(path) = env::args().nth(1) else {
-        println!("Usage: cargo run -- <file>");
+/// Hollow Knight and Silksong save file tool.
+#[derive(clap::Parser)]
+#[command(name = "hksave")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Decrypt a save file into pretty JSON.
+    Decode {
+        /// Save file to read, stdin if omitted or `-`.
+        input: Option<PathBuf>,
+        /// File to write, stdout if omitted or `-`.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Encrypt JSON into a save file the game can load.
+    Encode {
+        /// JSON file to read, stdin if omitted or `-`.
+        input: Option<PathBuf>,
+        /// File to write, stdout if omitted or `-`.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Change fields of a save file and write it back.
+    Edit {
+        /// Save file to read, stdin if omitted or `-`.
+        input: Option<PathBuf>,
+        /// `PATH=VALUE` to set `playerData.PATH`, VALUE is JSON or a plain
+        /// string, e.g. `hasSuperDash=false` or `geo=1000`.
+        #[arg(long = "set", value_name = "PATH=VALUE")]
+        fields: Vec<String>,
+        /// `SCENE:ID=BOOL` to set a `persistentBoolItems` entry, e.g.
+        /// `Crossroads_38:Heart Piece=true`.
+        #[arg(long = "scene", value_name = "SCENE:ID=BOOL")]
+        scene_items: Vec<String>,
+        /// Check id to make checked, along with the counters that go with
+        /// it, e.g. `charm.dashmaster`.
+        #[arg(long, value_name = "ID")]
+        grant: Vec<String>,
+        /// Check id to make unchecked, e.g. `equipment.crystal_heart`.
+        #[arg(long, value_name = "ID")]
+        revoke: Vec<String>,
+        /// File to write, stdout if omitted or `-`.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Print the checklist derived from a save file.
+    Checks {
+        /// Save file to read, stdin if omitted or `-`.
+        input: Option<PathBuf>,
+        #[arg(short, long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Print how much of every checklist category is done.
+    Percent {
+        /// Save file to read, stdin if omitted or `-`.
+        input: Option<PathBuf>,
+        #[arg(short, long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Print what changed between two save files of the same game.
+    Diff {
+        /// Older save file.
+        old: PathBuf,
+        /// Newer save file.
+        new: PathBuf,
+        #[arg(short, long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Watch a save file and print the checks that flip whenever the game
+    /// writes it, e.g. on every bench.
+    Watch {
+        /// Save file to watch.
+        input: PathBuf,
+        /// Print one JSON object per line for every flipped check.
+        #[arg(long)]
+        json: bool,
+        /// Milliseconds between looking at the file.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Serve a save file's checklist on localhost for overlays, like OBS
+    /// browser sources.
+    ///
+    /// `GET /checks`, `/percent` and `/stats` return the JSON output of those
+    /// commands for the latest version of the file. `/events` is a WebSocket
+    /// that sends the lines of `watch --json` as text messages.
+    Serve {
+        /// Save file to watch.
+        input: PathBuf,
+        /// Port to listen on at 127.0.0.1.
+        #[arg(short, long, default_value_t = 8000)]
+        port: u16,
+        /// Milliseconds between looking at the file.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Print statistics that aren't checks, like geo rocks broken per area
+    /// or the Hunter's Journal.
+    Stats {
+        /// Save file to read, stdin if omitted or `-`.
+        input: Option<PathBuf>,
+        #[arg(short, long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Print type definitions for the checklist output.
+    #[command(group(ArgGroup::new("kind").required(true)))]
+    Schema {
+        /// TypeScript definitions for the web app.
+        #[arg(long, group = "kind")]
+        ts: bool,
+        /// JSON Schema (draft 2020-12) of the JSON output.
+        #[arg(long, group = "kind")]
+        json_schema: bool,
+        /// File to write, stdout if omitted or `-`.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Json,
+    Table,
+    Markdown,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Decode { input, output } => {
+            let json = decrypt_to_json(&read(input.as_deref())?).map_err(|e| e.to_string())?;
+            let json = serde_json::to_string_pretty(&json).map_err(|e| e.to_string())?;
+            write(output.as_deref(), format!("{json}\n").as_bytes())
+        }
+        Command::Encode { input, output } => {
+            let json = read(input.as_deref())?;
+            // Catch typos before the game does
+            serde_json::from_slice::<Value>(&json).map_err(|e| format!("Invalid JSON: {e}"))?;
+            write(output.as_deref(), &encode_save_file(&json))
+        }
+        Command::Edit {
+            input,
+            fields,
+            scene_items,
+            grant,
+            revoke,
+            output,
+        } => {
+            let mut editor =
+                SaveEditor::from_save_file(&read(input.as_deref())?).map_err(|e| e.to_string())?;
+            for field in &fields {
+                let (path, value) = field
+                    .split_once('=')
+                    .ok_or_else(|| format!("Expected `PATH=VALUE`, found `{field}`"))?;
+                let value =
+                    serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_owned()));
+                editor
+                    .set_player_data(path, value)
+                    .map_err(|e| e.to_string())?;
+            }
+            for item in &scene_items {
+                let (scene, activated) = item
+                    .split_once(':')
+                    .and_then(|(scene, rest)| Some((scene, rest.rsplit_once('=')?)))
+                    .ok_or_else(|| format!("Expected `SCENE:ID=BOOL`, found `{item}`"))?;
+                let (id, activated) = activated;
+                let activated = activated
+                    .parse()
+                    .map_err(|_| format!("Expected `true` or `false`, found `{activated}`"))?;
+                editor
+                    .set_scene_item(scene, id, activated)
+                    .map_err(|e| e.to_string())?;
+            }
+            for id in &grant {
+                editor.grant_check(id).map_err(|e| e.to_string())?;
+            }
+            for id in &revoke {
+                editor.revoke_check(id).map_err(|e| e.to_string())?;
+            }
+            write(output.as_deref(), &editor.to_save_file())
+        }
+        Command::Checks { input, format } => {
+            let (map, _) = checks(&read(input.as_deref())?)?;
+            print(&render_checks(&map, format)?)
+        }
+        Command::Percent { input, format } => {
+            let (map, completion) = checks(&read(input.as_deref())?)?;
+            print(&render_percent(&map, completion.as_ref(), format)?)
+        }
+        Command::Diff { old, new, format } => {
+            let old = save_data(&read(Some(&old))?)?;
+            let new = save_data(&read(Some(&new))?)?;
+            let diff = diff_saves(&old, &new).ok_or("Saves are from different games")?;
+            print(&render_diff(&diff, format)?)
+        }
+        Command::Watch {
+            input,
+            json,
+            interval,
+        } => {
+            let mut watcher = Watcher::new(input)?;
+            if !json {
+                eprintln!("Watching `{}`", watcher.path.display());
+            }
+            loop {
+                thread::sleep(Duration::from_millis(interval));
+                match watcher.poll() {
+                    Ok(Some(diff)) => print(&render_flipped(&diff, json)?)?,
+                    Ok(None) => {}
+                    Err(e) => eprintln!("warning: {e}"),
+                }
+            }
+        }
+        Command::Serve {
+            input,
+            port,
+            interval,
+        } => {
+            let watcher = Arc::new(Mutex::new(Watcher::new(input)?));
+            let listener = TcpListener::bind(("127.0.0.1", port))
+                .map_err(|e| format!("Failed to listen on port {port}: {e}"))?;
+            let clients: Arc<Clients> = Arc::default();
+            {
+                let (watcher, clients) = (Arc::clone(&watcher), Arc::clone(&clients));
+                let path = lock(&watcher).path.clone();
+                thread::spawn(move || {
+                    loop {
+                        thread::sleep(Duration::from_millis(interval));
+                        // Parse without the lock, requests keep being
+                        // answered from the last snapshot meanwhile
+                        let changed = lock(&watcher).changed();
+                        let polled = match changed {
+                            Some(stamp) => {
+                                let parsed = load(&path);
+                                lock(&watcher).update(stamp, parsed)
+                            }
+                            None => Ok(None),
+                        };
+                        match polled
+                            .and_then(|diff| diff.map(|d| render_flipped(&d, true)).transpose())
+                        {
+                            Ok(Some(lines)) => broadcast(&clients, &lines),
+                            Ok(None) => {}
+                            Err(e) => eprintln!("warning: {e}"),
+                        }
+                    }
+                });
+            }
+            eprintln!(
+                "Serving `{}` at http://127.0.0.1:{port}",
+                lock(&watcher).path.display()
+            );
+            for stream in listener.incoming().flatten() {
+                let (watcher, clients) = (Arc::clone(&watcher), Arc::clone(&clients));
+                thread::spawn(move || respond(stream, &watcher, &clients));
+            }
+            Ok(())
+        }
+        Command::Stats { input, format } => {
+            let mut parser = Parser::new();
+            parser
+                .parse_save_file(&read(input.as_deref())?)
+                .map_err(|e| e.to_string())?;
+            let stats = parser
+                .get_statistics()
+                .ok_or("Statistics are only available for Hollow Knight saves")?;
+            print(&render_stats(&stats, &parser.get_map(), format)?)
+        }
+        Command::Schema {
+            ts,
+            json_schema: _,
+            output,
+        } => {
+            let schema = if ts {
+                typescript()
+            } else {
+                serde_json::to_string_pretty(&json_schema()).map_err(|e| e.to_string())? + "\n"
+            };
+            write(output.as_deref(), schema.as_bytes())
+        }
+    }
+}
+
+fn is_stdio(path: Option<&Path>) -> bool {
+    path.is_none_or(|p| p == Path::new("-"))
+}
+
+fn read(path: Option<&Path>) -> Result<Vec<u8>, String> {
+    match path {
+        Some(path) if !is_stdio(Some(path)) => {
+            fs::read(path).map_err(|e| format!("Failed to read file `{}`: {e}", path.display()))
+        }
+        _ => {
+            let mut data = Vec::new();
+            io::stdin()
+                .read_to_end(&mut data)
+                .map_err(|e| format!("Failed to read stdin: {e}"))?;
+            Ok(data)
+        }
+    }
+}
+
+fn write(path: Option<&Path>, data: &[u8]) -> Result<(), String> {
+    match path {
+        Some(path) if !is_stdio(Some(path)) => fs::write(path, data)
+            .map_err(|e| format!("Failed to write file `{}`: {e}", path.display())),
+        _ => io::stdout()
+            .write_all(data)
+            .map_err(|e| format!("Failed to write stdout: {e}")),
+    }
+}
+
+fn print(text: &str) -> Result<(), String> {
+    write(None, text.as_bytes())
+}
+
+/// Parses a save and returns its checklist along with the completion, if the
+/// game has one.
+fn checks(data: &[u8]) -> Result<(GameSer, Option<Completion>), String> {
+    let parser = parse(data)?;
+    Ok((parser.get_map(), parser.get_completion()))
+}
+
+fn save_data(data: &[u8]) -> Result<SaveData, String> {
+    parse(data)?
+        .get_save_data()
+        .ok_or_else(|| "Save wasn't parsed".to_owned())
+}
+
+fn parse(data: &[u8]) -> Result<Parser, String> {
+    let mut parser = Parser::new();
+    parser.parse_save_file(data).map_err(|e| e.to_string())?;
+    Ok(parser)
+}
+
+fn load(path: &Path) -> Result<(Parser, SaveData), String> {
+    let parser = parse(&read(Some(path))?)?;
+    let save = parser.get_save_data().ok_or("Save wasn't parsed")?;
+    Ok((parser, save))
+}
+
+/// Last good parse of a save file, re-parsed when the file changes.
+struct Watcher {
+    path: PathBuf,
+    /// Modification time and length the snapshot was taken at.
+    stamp: Option<(SystemTime, u64)>,
+    /// Stamp of the file the last time it didn't parse, so that it's only
+    /// reported once while it's retried.
+    failed: Option<(SystemTime, u64)>,
+    parser: Parser,
+    save: SaveData,
+}
+
+impl Watcher {
+    fn new(path: PathBuf) -> Result<Self, String> {
+        let stamp = stamp(&path);
+        let (parser, save) = load(&path)?;
+        Ok(Self {
+            path,
+            stamp,
+            failed: None,
+            parser,
+            save,
+        })
+    }
+
+    /// Re-parses the file if it changed since the last call and returns what
+    /// changed. On errors the last snapshot is kept, e.g. when the game was
+    /// halfway through writing the file, and the file is parsed again on the
+    /// next call: finishing the write doesn't have to change the stamp.
+    fn poll(&mut self) -> Result<Option<SaveDiff>, String> {
+        match self.changed() {
+            Some(stamp) => self.update(stamp, load(&self.path)),
+            None => Ok(None),
+        }
+    }
+
+    /// Stamp of the file if it changed since the snapshot.
+    fn changed(&self) -> Option<(SystemTime, u64)> {
+        // The game may replace the file rather than write it in place
+        stamp(&self.path).filter(|&stamp| Some(stamp) != self.stamp)
+    }
+
+    /// Takes the file parsed at `stamp` as the new snapshot, see [`Self::poll`].
+    fn update(
+        &mut self,
+        stamp: (SystemTime, u64),
+        parsed: Result<(Parser, SaveData), String>,
+    ) -> Result<Option<SaveDiff>, String> {
+        let (parser, save) = match parsed {
+            Ok(parsed) => parsed,
+            Err(_) if Some(stamp) == self.failed => return Ok(None),
+            Err(e) => {
+                self.failed = Some(stamp);
+                return Err(e);
+            }
+        };
+        self.stamp = Some(stamp);
+        self.failed = None;
+        let diff = diff_saves(&self.save, &save);
+        self.parser = parser;
+        self.save = save;
+        diff.map(Some)
+            .ok_or_else(|| "The file is a save of another game now, starting over".to_owned())
+    }
+}
+
+/// How long a connection to `serve` may take to send its request or to take
+/// a response or message before it's dropped.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often a WebSocket looks for lines to send between reads.
+const SEND_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Queues of lines for the WebSockets connected to `/events`, each sent by
+/// its own thread.
+type Clients = Mutex<Vec<Sender<String>>>;
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // Nothing is left half-updated by a panic while holding these
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Answers a connection to `serve`, one request per connection.
+fn respond(mut stream: TcpStream, watcher: &Mutex<Watcher>, clients: &Clients) {
+    let _ = stream.set_read_timeout(Some(TIMEOUT));
+    let _ = stream.set_write_timeout(Some(TIMEOUT));
+    let Ok(head) = read_head(&mut stream) else {
         return;
     };
-    let Ok(data) = fs::read(&path) else {
-        println!("Failed to read file `{path}`");
-        return;
+    let mut lines = head.lines();
+    let mut request = lines.next().unwrap_or_default().split(' ');
+    let (method, target) = (request.next().unwrap_or_default(), request.next());
+    let path = target
+        .unwrap_or_default()
+        .split('?')
+        .next()
+        .unwrap_or_default();
+    let headers: Vec<_> = lines.filter_map(|line| line.split_once(':')).collect();
+
+    let body = if method != "GET" {
+        Err((405, "Only GET is supported".to_owned()))
+    } else {
+        let watcher = lock(watcher);
+        let parser = &watcher.parser;
+        match path {
+            "/events" => {
+                drop(watcher);
+                let key = headers
+                    .iter()
+                    .find(|(name, _)| name.trim().eq_ignore_ascii_case("Sec-WebSocket-Key"))
+                    .map(|(_, key)| key.trim());
+                match key {
+                    Some(key) => return accept(stream, key, clients),
+                    None => Err((400, "Expected a WebSocket handshake".to_owned())),
+                }
+            }
+            "/checks" => render_checks(&parser.get_map(), Format::Json).map_err(|e| (500, e)),
+            "/percent" => render_percent(
+                &parser.get_map(),
+                parser.get_completion().as_ref(),
+                Format::Json,
+            )
+            .map_err(|e| (500, e)),
+            "/stats" => parser
+                .get_statistics()
+                .ok_or((
+                    404,
+                    "Statistics are only available for Hollow Knight saves".to_owned(),
+                ))
+                .and_then(|stats| {
+                    render_stats(&stats, &parser.get_map(), Format::Json).map_err(|e| (500, e))
+                }),
+            _ => Err((404, format!("No such endpoint `{path}`"))),
+        }
     };
-    println!("Parsing save file `{path}`");
-    let mut parser = Parser::new();
-    if parser.parse_save_file(&data).is_err() {
-        println!("Failed to parse save file");
+    let (status, body) = match body {
+        Ok(body) => (200, body),
+        Err((status, error)) => (status, json!({ "error": error }).to_string() + "\n"),
+    };
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Internal Server Error",
+    };
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    );
+}
+
+/// Reads a request up to the blank line after its headers. Requests to
+/// `serve` have no body, and WebSocket clients wait for the handshake before
+/// sending anything, so nothing past the head is read.
+fn read_head(stream: &mut TcpStream) -> io::Result<String> {
+    let mut head = Vec::new();
+    let mut buf = [0; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf)?;
+        if n == 0 || head.len() > 16 * 1024 {
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    String::from_utf8(head).map_err(|_| io::ErrorKind::InvalidData.into())
+}
+
+/// Upgrades a request to `/events` to a WebSocket, handled by its own thread
+/// so that a slow client doesn't hold up the others.
+fn accept(mut stream: TcpStream, key: &str, clients: &Clients) {
+    let key = derive_accept_key(key.as_bytes());
+    let handshake = write!(
+        stream,
+        "HTTP/1.1 101 Switching Protocols\r\n\
+         Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Accept: {key}\r\n\r\n"
+    );
+    if handshake.is_err() || stream.set_read_timeout(Some(SEND_INTERVAL)).is_err() {
         return;
     }
-    let map = parser.get_map();
-    println!("{map:#?}");
+    let (sender, lines) = mpsc::channel();
+    lock(clients).push(sender);
+    thread::spawn(move || {
+        let mut socket = WebSocket::from_raw_socket(stream, Role::Server, None);
+        loop {
+            match socket.read() {
+                // tungstenite answers pings and closes by itself, there's
+                // nothing else for clients to say
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(e))
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                    ) => {}
+                // Closed, or gone without closing
+                Err(_) => return,
+            }
+            loop {
+                match lines.try_recv() {
+                    Ok(line) => {
+                        if socket.send(Message::text(line)).is_err() {
+                            return;
+                        }
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => return,
+                }
+            }
+        }
+    });
+}
+
+/// Queues every line for every WebSocket, dropping the ones whose thread
+/// stopped.
+fn broadcast(clients: &Clients, lines: &str) {
+    lock(clients).retain(|sender| {
+        lines
+            .lines()
+            .all(|line| sender.send(line.to_owned()).is_ok())
+    });
+}
+
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Serialized name of the game and its checklist.
+fn game(map: &GameSer) -> (&'static str, &Checklist) {
+    match map {
+        GameSer::HollowKnight(checks) => ("hollow-knight", checks),
+        GameSer::Silksong(checks) => ("silksong", checks),
+    }
+}
+
+fn render_checks(map: &GameSer, format: Format) -> Result<String, String> {
+    let (game, checklist) = game(map);
+    let mut out = String::new();
+    match format {
+        Format::Json => {
+            out = serde_json::to_string_pretty(map).map_err(|e| e.to_string())? + "\n";
+        }
+        Format::Table => {
+            for (name, checks) in checklist.iter() {
+                let _ = writeln!(out, "{name}");
+                for check in checks.values() {
+                    let mark = if check.checked { 'x' } else { ' ' };
+                    let _ = writeln!(out, "  [{mark}] {}", check.label);
+                }
+            }
+        }
+        Format::Markdown => {
+            let _ = writeln!(out, "# {game}");
+            for (name, checks) in checklist.iter() {
+                let _ = writeln!(out, "\n## {name}\n");
+                for check in checks.values() {
+                    let mark = if check.checked { 'x' } else { ' ' };
+                    let _ = writeln!(out, "- [{mark}] {}", check.label);
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn render_percent(
+    map: &GameSer,
+    completion: Option<&Completion>,
+    format: Format,
+) -> Result<String, String> {
+    let (game, checklist) = game(map);
+    let counts: Vec<_> = checklist
+        .iter()
+        .map(|(name, checks)| {
+            let done = checks.values().filter(|check| check.checked).count();
+            (name, done, checks.len())
+        })
+        .collect();
+    let done: usize = counts.iter().map(|(_, done, _)| done).sum();
+    let total: usize = counts.iter().map(|(_, _, total)| total).sum();
+    let percent = |name: &str| {
+        completion
+            .and_then(|c| c.categories.iter().find(|c| c.category == name))
+            .map(|c| format!("{}%/{}%", c.percent, c.max))
+            .unwrap_or_default()
+    };
+
+    let mut out = String::new();
+    match format {
+        Format::Json => {
+            #[derive(Serialize)]
+            struct Count {
+                done: usize,
+                total: usize,
+            }
+
+            #[derive(Serialize)]
+            struct Percent<'a> {
+                game: &'a str,
+                done: usize,
+                total: usize,
+                categories: IndexMap<&'a str, Count>,
+                completion: Option<&'a Completion>,
+            }
+
+            let json = Percent {
+                game,
+                done,
+                total,
+                categories: counts
+                    .iter()
+                    .map(|&(name, done, total)| (name, Count { done, total }))
+                    .collect(),
+                completion,
+            };
+            out = serde_json::to_string_pretty(&json).map_err(|e| e.to_string())? + "\n";
+        }
+        Format::Table => {
+            let width = counts
+                .iter()
+                .map(|(name, ..)| name.len())
+                .max()
+                .unwrap_or(0);
+            for (name, done, total) in &counts {
+                let percent = percent(name);
+                let _ = writeln!(out, "{name:width$}  {done:>3}/{total:<3}  {percent}");
+            }
+            let _ = writeln!(out, "{:width$}  {done:>3}/{total:<3}", "total");
+            if let Some(c) = completion {
+                let _ = writeln!(
+                    out,
+                    "\ncompletion {}% (game reports {}%, off by {}%)",
+                    c.percent, c.reported, c.discrepancy
+                );
+            }
+        }
+        Format::Markdown => {
+            let _ = writeln!(out, "| Category | Done | Total | Percent |");
+            let _ = writeln!(out, "| --- | ---: | ---: | ---: |");
+            for (name, done, total) in &counts {
+                let percent = percent(name);
+                let _ = writeln!(out, "| {name} | {done} | {total} | {percent} |");
+            }
+            let percent = completion.map(|c| format!("{}%", c.percent));
+            let percent = percent.unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "| **total** | **{done}** | **{total}** | **{percent}** |"
+            );
+            if let Some(c) = completion {
+                let _ = writeln!(
+                    out,
+                    "\nThe game reports {}%, off by {}%.",
+                    c.reported, c.discrepancy
+                );
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn render_stats(stats: &Statistics, map: &GameSer, format: Format) -> Result<String, String> {
+    let rocks = &stats.geo_rocks;
+    let broken: usize = rocks.iter().map(|a| a.broken).sum();
+    let found: usize = rocks.iter().map(|a| a.found).sum();
+    let hits_left: i32 = rocks.iter().map(|a| a.hits_left).sum();
+    let journal = &stats.hunters_journal;
+    let completed = journal.iter().filter(|e| e.completed).count();
+    let status = |e: &JournalEntry| match (e.completed, e.discovered) {
+        (true, _) => "completed",
+        (false, true) => "discovered",
+        (false, false) => "",
+    };
+    // Achievements by their label rather than their id
+    let (_, checklist) = game(map);
+    let achievements: Vec<_> = stats
+        .achievements
+        .iter()
+        .map(|a| {
+            let label = checklist
+                .category("achievements")
+                .and_then(|checks| checks.get(a.id))
+                .map_or(a.id, |check| check.label.as_str());
+            let name = label.split_once(':').map_or(label, |(name, _)| name);
+            (name, a.done, a.total)
+        })
+        .collect();
+
+    let mut out = String::new();
+    match format {
+        Format::Json => {
+            out = serde_json::to_string_pretty(stats).map_err(|e| e.to_string())? + "\n";
+        }
+        Format::Table => {
+            let width = rocks
+                .iter()
+                .map(|a| a.area.len())
+                .chain(["geo rocks".len()])
+                .max()
+                .unwrap_or(0);
+            let _ = writeln!(out, "{:width$}  broken  hits left", "geo rocks");
+            for a in rocks {
+                let (area, broken, found, hits_left) = (a.area, a.broken, a.found, a.hits_left);
+                let _ = writeln!(out, "{area:width$}  {broken:>3}/{found:<3}  {hits_left:>4}");
+            }
+            let _ = writeln!(
+                out,
+                "{:width$}  {broken:>3}/{found:<3}  {hits_left:>4}",
+                "total"
+            );
+
+            let width = journal
+                .iter()
+                .map(|e| e.label().len())
+                .chain(["hunter's journal".len()])
+                .max()
+                .unwrap_or(0);
+            let _ = writeln!(
+                out,
+                "\n{:width$}  {:10}  kills left",
+                "hunter's journal", ""
+            );
+            for e in journal {
+                let (label, status, kills_left) = (e.label(), status(e), e.kills_left);
+                let _ = writeln!(out, "{label:width$}  {status:10}  {kills_left:>4}");
+            }
+            let _ = writeln!(
+                out,
+                "{:width$}  {completed}/{} completed",
+                "total",
+                journal.len()
+            );
+
+            let width = achievements
+                .iter()
+                .map(|(name, ..)| name.len())
+                .chain(["achievements".len()])
+                .max()
+                .unwrap_or(0);
+            let _ = writeln!(out, "\n{:width$}  progress", "achievements");
+            for (name, done, total) in &achievements {
+                let _ = writeln!(out, "{name:width$}  {done:>3}/{total}");
+            }
+        }
+        Format::Markdown => {
+            let _ = writeln!(out, "## Geo rocks\n");
+            let _ = writeln!(out, "| Area | Broken | Found | Hits left |");
+            let _ = writeln!(out, "| --- | ---: | ---: | ---: |");
+            for a in rocks {
+                let _ = writeln!(
+                    out,
+                    "| {} | {} | {} | {} |",
+                    a.area, a.broken, a.found, a.hits_left
+                );
+            }
+            let _ = writeln!(
+                out,
+                "| **total** | **{broken}** | **{found}** | **{hits_left}** |"
+            );
+
+            let _ = writeln!(out, "\n## Hunter's Journal\n");
+            let _ = writeln!(out, "| Enemy | Status | Kills left |");
+            let _ = writeln!(out, "| --- | --- | ---: |");
+            for e in journal {
+                let _ = writeln!(out, "| {} | {} | {} |", e.label(), status(e), e.kills_left);
+            }
+            let _ = writeln!(
+                out,
+                "| **total** | **{completed}/{} completed** | |",
+                journal.len()
+            );
+
+            let _ = writeln!(out, "\n## Achievements\n");
+            let _ = writeln!(out, "| Achievement | Done | Total |");
+            let _ = writeln!(out, "| --- | ---: | ---: |");
+            for (name, done, total) in &achievements {
+                let _ = writeln!(out, "| {name} | {done} | {total} |");
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn render_diff(diff: &SaveDiff, format: Format) -> Result<String, String> {
+    let mut out = String::new();
+    match format {
+        Format::Json => {
+            out = serde_json::to_string_pretty(diff).map_err(|e| e.to_string())? + "\n";
+        }
+        Format::Table => {
+            if diff.is_empty() {
+                let _ = writeln!(out, "no changes");
+            }
+            for (category, changes) in &diff.checks {
+                let _ = writeln!(out, "{category}");
+                for change in changes {
+                    let mark = if change.checked { '+' } else { '-' };
+                    let _ = writeln!(out, "  {mark} {}", change.label);
+                }
+            }
+            if !diff.player_data.is_empty() {
+                let _ = writeln!(out, "playerData");
+                for change in &diff.player_data {
+                    let _ = writeln!(out, "  {}: {} -> {}", change.path, change.old, change.new);
+                }
+            }
+            if !diff.activated.is_empty() {
+                let _ = writeln!(out, "activated");
+                for item in &diff.activated {
+                    let _ = writeln!(out, "  {}: {}", item.scene_name, item.id);
+                }
+            }
+        }
+        Format::Markdown => {
+            let _ = writeln!(out, "# Save diff");
+            for (category, changes) in &diff.checks {
+                let _ = writeln!(out, "\n## {category}\n");
+                for change in changes {
+                    let mark = if change.checked { 'x' } else { ' ' };
+                    let _ = writeln!(out, "- [{mark}] {}", change.label);
+                }
+            }
+            if !diff.player_data.is_empty() {
+                let _ = writeln!(out, "\n## playerData\n");
+                let _ = writeln!(out, "| Field | Old | New |");
+                let _ = writeln!(out, "| --- | --- | --- |");
+                for change in &diff.player_data {
+                    let _ = writeln!(
+                        out,
+                        "| `{}` | `{}` | `{}` |",
+                        change.path, change.old, change.new
+                    );
+                }
+            }
+            if !diff.activated.is_empty() {
+                let _ = writeln!(out, "\n## Activated\n");
+                for item in &diff.activated {
+                    let _ = writeln!(out, "- {}: {}", item.scene_name, item.id);
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Checks that flipped, one line each, for a live feed.
+fn render_flipped(diff: &SaveDiff, json: bool) -> Result<String, String> {
+    #[derive(Serialize)]
+    struct Flipped<'a> {
+        category: &'a str,
+        #[serde(flatten)]
+        change: &'a CheckChange,
+    }
+
+    let mut out = String::new();
+    for (&category, changes) in &diff.checks {
+        for change in changes {
+            if json {
+                let line = serde_json::to_string(&Flipped { category, change })
+                    .map_err(|e| e.to_string())?;
+                let _ = writeln!(out, "{line}");
+            } else {
+                let mark = if change.checked { '+' } else { '-' };
+                let _ = writeln!(out, "{mark} {category}: {}", change.label);
+            }
+        }
+    }
+    Ok(out)
 }
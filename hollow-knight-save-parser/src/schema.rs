@@ -0,0 +1,191 @@
+use serde_json::{Map, Value, json};
+use std::fmt::Write as _;
+
+use crate::{CategoryDef, Game, hollow_knight, silksong};
+
+/// Every game `GameSer` can hold: type name prefix, serialized tag and its
+/// checks.
+const GAMES: &[(Game, &str, &str, &[CategoryDef])] = &[
+    (
+        Game::HollowKnight,
+        "HollowKnight",
+        "hollow-knight",
+        hollow_knight::CHECKS,
+    ),
+    (Game::Silksong, "Silksong", "silksong", silksong::CHECKS),
+];
+
+/// Line width the frontend's prettier config wraps at.
+const WIDTH: usize = 80;
+
+/// TypeScript definitions for `Parser.get_map`'s output: every category of
+/// every game with its exact check ids, and the labels the web app keys its
+/// own checklist by. Derived categories aren't in the web app's checklist.
+#[must_use]
+pub fn typescript() -> String {
+    let mut out = String::from("// Generated by `hksave schema --ts`, do not edit.\n");
+
+    let _ = writeln!(out, "\n/** State of a single check in a parsed save. */");
+    let _ = writeln!(out, "export type CheckState = {{");
+    let _ = writeln!(out, "    /** Plain text label, without the wiki markup. */");
+    let _ = writeln!(out, "    label: string;");
+    let _ = writeln!(out, "    /** Wiki pages the label links to. */");
+    let _ = writeln!(out, "    wiki: string[];");
+    let _ = writeln!(out, "    checked: boolean;");
+    let _ = writeln!(out, "}};");
+
+    for (game, name, _, categories) in GAMES {
+        let _ = writeln!(out, "\n/** {game} check labels, by planner category. */");
+        let _ = writeln!(out, "export type {name}ChecksKeys = {{");
+        for category in categories.iter().filter(|c| !c.derived) {
+            let labels: Vec<_> = category.checks.iter().map(|c| c.label).collect();
+            union(&mut out, category.name, &labels);
+        }
+        let _ = writeln!(out, "}};");
+
+        let _ = writeln!(out, "\n/** {game} check ids, by category. */");
+        let _ = writeln!(out, "export type {name}CheckIds = {{");
+        for category in *categories {
+            let ids: Vec<_> = category.checks.iter().map(|c| c.id).collect();
+            union(&mut out, category.name, &ids);
+        }
+        let _ = writeln!(out, "}};");
+
+        let _ = writeln!(
+            out,
+            "\n/** {game} checklist, as returned by `Parser.get_map`. */"
+        );
+        let _ = writeln!(out, "export type {name}Checks = {{");
+        let _ = writeln!(out, "    [Category in keyof {name}CheckIds]: Record<");
+        let _ = writeln!(out, "        {name}CheckIds[Category],");
+        let _ = writeln!(out, "        CheckState");
+        let _ = writeln!(out, "    >;");
+        let _ = writeln!(out, "}};");
+    }
+
+    let _ = writeln!(
+        out,
+        "\n/** Checklist of whichever game the save belongs to. */"
+    );
+    let _ = writeln!(out, "export type GameSer =");
+    for (i, (_, name, tag, _)) in GAMES.iter().enumerate() {
+        let end = if i + 1 == GAMES.len() { ";" } else { "" };
+        let tag = if tag.contains('-') {
+            quote(tag)
+        } else {
+            (*tag).to_owned()
+        };
+        let _ = writeln!(out, "    | {{ {tag}: {name}Checks }}{end}");
+    }
+    out
+}
+
+/// Writes `category: 'a' | 'b';`, wrapped like prettier would.
+fn union(out: &mut String, category: &str, keys: &[&str]) {
+    let keys: Vec<_> = keys.iter().map(|key| quote(key)).collect();
+    let line = if keys.is_empty() {
+        // Filled in from the save, anything goes
+        format!("    {category}: string;")
+    } else {
+        format!("    {category}: {};", keys.join(" | "))
+    };
+    if line.len() <= WIDTH {
+        let _ = writeln!(out, "{line}");
+    } else {
+        let _ = writeln!(out, "    {category}:");
+        for (i, key) in keys.iter().enumerate() {
+            let end = if i + 1 == keys.len() { ";" } else { "" };
+            let _ = writeln!(out, "        | {key}{end}");
+        }
+    }
+}
+
+/// JSON Schema (draft 2020-12) for `Parser.get_map`'s output serialized as
+/// JSON: every category of every game with its exact check ids.
+#[must_use]
+pub fn json_schema() -> Value {
+    let mut defs = Map::new();
+    let mut variants = Vec::new();
+    defs.insert(
+        "CheckState".to_owned(),
+        json!({
+            "description": "State of a single check in a parsed save.",
+            "type": "object",
+            "properties": {
+                "label": {
+                    "description": "Plain text label, without the wiki markup.",
+                    "type": "string",
+                },
+                "wiki": {
+                    "description": "Wiki pages the label links to.",
+                    "type": "array",
+                    "items": { "type": "string" },
+                },
+                "checked": { "type": "boolean" },
+            },
+            "required": ["label", "wiki", "checked"],
+            "additionalProperties": false,
+        }),
+    );
+
+    for (game, name, tag, categories) in GAMES {
+        let properties: Map<_, _> = categories
+            .iter()
+            .map(|category| {
+                let keys: Vec<_> = category.checks.iter().map(|c| c.id).collect();
+                let mut schema = json!({
+                    "type": "object",
+                    "additionalProperties": { "$ref": "#/$defs/CheckState" },
+                });
+                // Categories filled in from the save can have any keys
+                if !keys.is_empty() {
+                    schema["propertyNames"] = json!({ "enum": keys });
+                    schema["required"] = json!(keys);
+                }
+                (category.name.to_owned(), schema)
+            })
+            .collect();
+        let required: Vec<_> = categories.iter().map(|c| c.name).collect();
+        defs.insert(
+            format!("{name}Checks"),
+            json!({
+                "description": format!("{game} checklist, by category."),
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            }),
+        );
+        variants.push(json!({
+            "type": "object",
+            "properties": { *tag: { "$ref": format!("#/$defs/{name}Checks") } },
+            "required": [tag],
+            "additionalProperties": false,
+        }));
+    }
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$comment": concat!(
+            "Generated by `hksave schema --json-schema` from ",
+            "hollow-knight-save-parser ",
+            env!("CARGO_PKG_VERSION"),
+            ", do not edit."
+        ),
+        "title": "GameSer",
+        "description": "Checklist of whichever game the save belongs to.",
+        "oneOf": variants,
+        "$defs": defs,
+    })
+}
+
+/// Quotes a string literal the way prettier would: single quotes unless
+/// that means escaping more.
+fn quote(s: &str) -> String {
+    let s = s.replace('\\', "\\\\");
+    if s.matches('\'').count() > s.matches('"').count() {
+        format!("\"{}\"", s.replace('"', "\\\""))
+    } else {
+        format!("'{}'", s.replace('\'', "\\'"))
+    }
+}
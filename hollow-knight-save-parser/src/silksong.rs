@@ -0,0 +1,843 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::Number;
+use crate::checks::{
+    CategoryDef, CheckDef, Checklist,
+    Predicate::{self, AtLeast, Flag, Pieces, Unlocked},
+};
+
+pub type SilksongChecks = Checklist;
+
+const fn check(id: &'static str, label: &'static str, predicate: Predicate) -> CheckDef {
+    CheckDef::new(id, label, predicate)
+}
+
+/// At least `n` mask shards, four to a mask on top of the starting five.
+const fn mask_shards(n: Number) -> Predicate {
+    Pieces {
+        pieces: "heartPieces",
+        whole: "maxHealthBase",
+        base: 5.0,
+        per: 4.0,
+        n,
+    }
+}
+
+/// At least `n` spool fragments, two to a unit of silk on top of the
+/// starting nine.
+const fn spool_parts(n: Number) -> Predicate {
+    Pieces {
+        pieces: "silkSpoolParts",
+        whole: "silkMax",
+        base: 9.0,
+        per: 2.0,
+        n,
+    }
+}
+
+/// Every Silksong check, in the order the checklist lists them.
+pub const CHECKS: &[CategoryDef] = &[
+    CategoryDef::new("bosses", BOSSES),
+    CategoryDef::new("abilities", ABILITIES),
+    CategoryDef::new("needle", NEEDLE),
+    CategoryDef::new("silkSkills", SILK_SKILLS),
+    CategoryDef::new("tools", TOOLS),
+    CategoryDef::new("crests", CRESTS),
+    CategoryDef::new("toolUpgrades", TOOL_UPGRADES),
+    // The save only tracks how many pieces were collected, not where they
+    // were found. Whether the piece counters restart at every new mask or
+    // spool isn't known for sure, see `Pieces`.
+    CategoryDef::new("maskShards", MASK_SHARDS),
+    CategoryDef::new("spoolFragments", SPOOL_FRAGMENTS),
+    CategoryDef::new("silkHearts", SILK_HEARTS),
+    // Wishes are stored by their internal names, which are stable enough to
+    // be used as keys directly.
+    CategoryDef::new("quests", &[]),
+];
+
+const BOSSES: &[CheckDef] = &[
+    check(
+        "boss.moss_mother",
+        "[Moss Mother]",
+        Flag("defeatedMossMother"),
+    ),
+    check("boss.bell_beast", "[Bell Beast]", Flag("defeatedBellBeast")),
+    check("boss.lace_1", "[Lace] #1", Flag("defeatedLace1")),
+    check(
+        "boss.fourth_chorus",
+        "[Fourth Chorus]",
+        Flag("defeatedSongGolem"),
+    ),
+    check(
+        "boss.savage_beastfly",
+        "[Savage Beastfly]",
+        Flag("defeatedBoneFlyerGiant"),
+    ),
+    check(
+        "boss.sister_splinter",
+        "[Sister Splinter]",
+        Flag("defeatedSplinterQueen"),
+    ),
+    check(
+        "boss.skull_tyrant",
+        "[Skull Tyrant]",
+        Flag("skullKingDefeated"),
+    ),
+    check("boss.widow", "[Widow]", Flag("spinnerDefeated")),
+    check(
+        "boss.moorwing",
+        "[Moorwing]",
+        Flag("defeatedVampireGnatBoss"),
+    ),
+    check("boss.phantom", "[Phantom]", Flag("defeatedPhantom")),
+    check("boss.last_judge", "[Last Judge]", Flag("defeatedLastJudge")),
+    check(
+        "boss.cogwork_dancers",
+        "[Cogwork Dancers]",
+        Flag("defeatedCogworkDancers"),
+    ),
+    check("boss.trobbio", "[Trobbio]", Flag("defeatedTrobbio")),
+    check(
+        "boss.forebrothers_signis_and_gron",
+        "[Forebrothers Signis & Gron]",
+        Flag("defeatedDockForemen"),
+    ),
+    check(
+        "boss.disgraced_chef_lugoli",
+        "[Disgraced Chef Lugoli]",
+        Flag("defeatedRoachkeeperChef"),
+    ),
+    check(
+        "boss.father_of_the_flame",
+        "[Father of the Flame]",
+        Flag("defeatedWispPyreEffigy"),
+    ),
+    check(
+        "boss.groal_the_great",
+        "[Groal the Great]",
+        Flag("defeatedSwampShaman"),
+    ),
+    check(
+        "boss.raging_conchfly",
+        "[Raging Conchfly]",
+        Flag("defeatedCoralDrillerSolo"),
+    ),
+    check("boss.lace_2", "[Lace] #2", Flag("defeatedLaceTower")),
+];
+
+const ABILITIES: &[CheckDef] = &[
+    check("ability.swift_step", "[Swift Step]", Flag("hasDash")),
+    check("ability.cling_grip", "[Cling Grip]", Flag("hasWalljump")),
+    check("ability.needolin", "[Needolin]", Flag("hasNeedolin")),
+    check("ability.clawline", "[Clawline]", Flag("hasHarpoonDash")),
+    check(
+        "ability.needle_strike",
+        "[Needle Strike]",
+        Flag("hasChargeSlash"),
+    ),
+    check(
+        "ability.drifters_cloak",
+        "[Drifter's Cloak]",
+        Flag("hasBrolly"),
+    ),
+    check(
+        "ability.faydown_cloak",
+        "[Faydown Cloak]",
+        Flag("hasDoubleJump"),
+    ),
+    check("ability.silk_soar", "[Silk Soar]", Flag("hasSuperJump")),
+    check(
+        "ability.elegy_of_the_deep",
+        "[Elegy of the Deep]",
+        Flag("hasNeedolinMemoryPowerup"),
+    ),
+];
+
+const NEEDLE: &[CheckDef] = &[
+    check(
+        "needle.sharpened_needle",
+        "[Sharpened Needle](Needle#Upgrades)",
+        AtLeast("nailUpgrades", 1.0),
+    ),
+    check(
+        "needle.shining_needle",
+        "[Shining Needle](Needle#Upgrades)",
+        AtLeast("nailUpgrades", 2.0),
+    ),
+    check(
+        "needle.hivesteel_needle",
+        "[Hivesteel Needle](Needle#Upgrades)",
+        AtLeast("nailUpgrades", 3.0),
+    ),
+    check(
+        "needle.pale_steel_needle",
+        "[Pale Steel Needle](Needle#Upgrades)",
+        AtLeast("nailUpgrades", 4.0),
+    ),
+];
+
+const SILK_SKILLS: &[CheckDef] = &[
+    check(
+        "silk_skill.silkspear",
+        "[Silkspear]",
+        Unlocked("Tools", "Silk Spear"),
+    ),
+    check(
+        "silk_skill.thread_storm",
+        "[Thread Storm]",
+        Unlocked("Tools", "Thread Sphere"),
+    ),
+    check(
+        "silk_skill.cross_stitch",
+        "[Cross Stitch]",
+        Unlocked("Tools", "Parry"),
+    ),
+    check(
+        "silk_skill.sharpdart",
+        "[Sharpdart]",
+        Unlocked("Tools", "Silk Charge"),
+    ),
+    check(
+        "silk_skill.rune_rage",
+        "[Rune Rage]",
+        Unlocked("Tools", "Silk Bomb"),
+    ),
+    check(
+        "silk_skill.pale_nails",
+        "[Pale Nails]",
+        Unlocked("Tools", "Silk Boss Needle"),
+    ),
+];
+
+const TOOLS: &[CheckDef] = &[
+    check(
+        "tool.straight_pin",
+        "[Straight Pin]",
+        Unlocked("Tools", "Straight Pin"),
+    ),
+    check(
+        "tool.threefold_pin",
+        "[Threefold Pin]",
+        Unlocked("Tools", "Tri Pin"),
+    ),
+    check(
+        "tool.sting_shard",
+        "[Sting Shard]",
+        Unlocked("Tools", "Sting Shard"),
+    ),
+    check("tool.tacks", "[Tacks]", Unlocked("Tools", "Tack")),
+    check("tool.longpin", "[Longpin]", Unlocked("Tools", "Harpoon")),
+    check(
+        "tool.curveclaw",
+        "[Curveclaw]",
+        Unlocked("Tools", "Curve Claws"),
+    ),
+    check(
+        "tool.curvesickle",
+        "[Curvesickle]",
+        Unlocked("Tools", "Curve Claws Upgraded"),
+    ),
+    check(
+        "tool.throwing_ring",
+        "[Throwing Ring]",
+        Unlocked("Tools", "Shakra Ring"),
+    ),
+    check("tool.pimpillo", "[Pimpillo]", Unlocked("Tools", "Pimpilo")),
+    check(
+        "tool.conchcutter",
+        "[Conchcutter]",
+        Unlocked("Tools", "Conch Drill"),
+    ),
+    check(
+        "tool.silkshot",
+        "[Silkshot]",
+        Unlocked("Tools", "WebShot Forge"),
+    ),
+    check(
+        "tool.delvers_drill",
+        "[Delver's Drill]",
+        Unlocked("Tools", "Screw Attack"),
+    ),
+    check(
+        "tool.cogwork_wheel",
+        "[Cogwork Wheel]",
+        Unlocked("Tools", "Cogwork Saw"),
+    ),
+    check(
+        "tool.cogfly",
+        "[Cogfly]",
+        Unlocked("Tools", "Cogwork Flier"),
+    ),
+    check(
+        "tool.rosary_cannon",
+        "[Rosary Cannon]",
+        Unlocked("Tools", "Rosary Cannon"),
+    ),
+    check(
+        "tool.voltvessels",
+        "[Voltvessels]",
+        Unlocked("Tools", "Lightning Rod"),
+    ),
+    check(
+        "tool.flintslate",
+        "[Flintslate]",
+        Unlocked("Tools", "Flintstone"),
+    ),
+    check(
+        "tool.snare_setter",
+        "[Snare Setter]",
+        Unlocked("Tools", "Silk Snare"),
+    ),
+    check(
+        "tool.flea_brew",
+        "[Flea Brew]",
+        Unlocked("Tools", "Flea Brew"),
+    ),
+    check(
+        "tool.plasmium_phial",
+        "[Plasmium Phial]",
+        Unlocked("Tools", "Lifeblood Syringe"),
+    ),
+    check(
+        "tool.needle_phial",
+        "[Needle Phial]",
+        Unlocked("Tools", "Extractor"),
+    ),
+    check(
+        "tool.druids_eye",
+        "[Druid's Eye]",
+        Unlocked("Tools", "Mosscreep Tool 1"),
+    ),
+    check(
+        "tool.druids_eyes",
+        "[Druid's Eyes]",
+        Unlocked("Tools", "Mosscreep Tool 2"),
+    ),
+    check(
+        "tool.magma_bell",
+        "[Magma Bell]",
+        Unlocked("Tools", "Lava Charm"),
+    ),
+    check(
+        "tool.warding_bell",
+        "[Warding Bell]",
+        Unlocked("Tools", "Bell Bind"),
+    ),
+    check(
+        "tool.pollip_pouch",
+        "[Pollip Pouch]",
+        Unlocked("Tools", "Poison Pouch"),
+    ),
+    check(
+        "tool.fractured_mask",
+        "[Fractured Mask]",
+        Unlocked("Tools", "Fractured Mask"),
+    ),
+    check(
+        "tool.multibinder",
+        "[Multibinder]",
+        Unlocked("Tools", "Multibind"),
+    ),
+    check(
+        "tool.weavelight",
+        "[Weavelight]",
+        Unlocked("Tools", "White Ring"),
+    ),
+    check(
+        "tool.sawtooth_circlet",
+        "[Sawtooth Circlet]",
+        Unlocked("Tools", "Brolly Spike"),
+    ),
+    check(
+        "tool.injector_band",
+        "[Injector Band]",
+        Unlocked("Tools", "Quickbind"),
+    ),
+    check(
+        "tool.spool_extender",
+        "[Spool Extender]",
+        Unlocked("Tools", "Spool Extender"),
+    ),
+    check(
+        "tool.reserve_bind",
+        "[Reserve Bind]",
+        Unlocked("Tools", "Reserve Bind"),
+    ),
+    check(
+        "tool.claw_mirror",
+        "[Claw Mirror]",
+        Unlocked("Tools", "Dazzle Bind"),
+    ),
+    check(
+        "tool.memory_crystal",
+        "[Memory Crystal]",
+        Unlocked("Tools", "Revenge Crystal"),
+    ),
+    check(
+        "tool.snitch_pick",
+        "[Snitch Pick]",
+        Unlocked("Tools", "Thief Claw"),
+    ),
+    check(
+        "tool.volt_filament",
+        "[Volt Filament]",
+        Unlocked("Tools", "Zap Imbuement"),
+    ),
+    check(
+        "tool.quick_sling",
+        "[Quick Sling]",
+        Unlocked("Tools", "Quick Sling"),
+    ),
+    check(
+        "tool.wreath_of_purity",
+        "[Wreath of Purity]",
+        Unlocked("Tools", "Maggot Charm"),
+    ),
+    check(
+        "tool.longclaw",
+        "[Longclaw]",
+        Unlocked("Tools", "Longneedle"),
+    ),
+    check(
+        "tool.wispfire_lantern",
+        "[Wispfire Lantern]",
+        Unlocked("Tools", "Wisp Lantern"),
+    ),
+    check(
+        "tool.egg_of_flealia",
+        "[Egg of Flealia]",
+        Unlocked("Tools", "Flea Charm"),
+    ),
+    check(
+        "tool.pin_badge",
+        "[Pin Badge]",
+        Unlocked("Tools", "Pinstress Tool"),
+    ),
+    check("tool.compass", "[Compass]", Unlocked("Tools", "Compass")),
+    check(
+        "tool.shard_pendant",
+        "[Shard Pendant]",
+        Unlocked("Tools", "Bone Necklace"),
+    ),
+    check(
+        "tool.magnetite_brooch",
+        "[Magnetite Brooch]",
+        Unlocked("Tools", "Rosary Magnet"),
+    ),
+    check(
+        "tool.weighted_belt",
+        "[Weighted Belt]",
+        Unlocked("Tools", "Weighted Belt"),
+    ),
+    check(
+        "tool.barbed_bracelet",
+        "[Barbed Bracelet]",
+        Unlocked("Tools", "Barbed Wire"),
+    ),
+    check(
+        "tool.dead_bugs_purse",
+        "[Dead Bug's Purse]",
+        Unlocked("Tools", "Dead Mans Purse"),
+    ),
+    check(
+        "tool.shell_satchel",
+        "[Shell Satchel]",
+        Unlocked("Tools", "Shell Satchel"),
+    ),
+    check(
+        "tool.magnetite_dice",
+        "[Magnetite Dice]",
+        Unlocked("Tools", "Magnetite Dice"),
+    ),
+    check(
+        "tool.scuttlebrace",
+        "[Scuttlebrace]",
+        Unlocked("Tools", "Scuttlebrace"),
+    ),
+    check(
+        "tool.ascendants_grip",
+        "[Ascendant's Grip]",
+        Unlocked("Tools", "Wallcling"),
+    ),
+    check(
+        "tool.spider_strings",
+        "[Spider Strings]",
+        Unlocked("Tools", "Musician Charm"),
+    ),
+    check(
+        "tool.silkspeed_anklets",
+        "[Silkspeed Anklets]",
+        Unlocked("Tools", "Sprintmaster"),
+    ),
+    check(
+        "tool.thiefs_mark",
+        "[Thief's Mark]",
+        Unlocked("Tools", "Thief Charm"),
+    ),
+];
+
+const CRESTS: &[CheckDef] = &[
+    check(
+        "crest.hunter",
+        "[Crest of the Hunter]",
+        Unlocked("ToolEquips", "Hunter"),
+    ),
+    check(
+        "crest.reaper",
+        "[Crest of the Reaper]",
+        Unlocked("ToolEquips", "Reaper"),
+    ),
+    check(
+        "crest.wanderer",
+        "[Crest of the Wanderer]",
+        Unlocked("ToolEquips", "Wanderer"),
+    ),
+    check(
+        "crest.beast",
+        "[Crest of the Beast]",
+        Unlocked("ToolEquips", "Warrior"),
+    ),
+    check(
+        "crest.witch",
+        "[Crest of the Witch]",
+        Unlocked("ToolEquips", "Witch"),
+    ),
+    check(
+        "crest.architect",
+        "[Crest of the Architect]",
+        Unlocked("ToolEquips", "Toolmaster"),
+    ),
+    check(
+        "crest.shaman",
+        "[Crest of the Shaman]",
+        Unlocked("ToolEquips", "Spell"),
+    ),
+];
+
+const TOOL_UPGRADES: &[CheckDef] = &[
+    check(
+        "tool_upgrade.tool_pouch_1",
+        "[Tool Pouch] #1",
+        AtLeast("ToolPouchUpgrades", 1.0),
+    ),
+    check(
+        "tool_upgrade.tool_pouch_2",
+        "[Tool Pouch] #2",
+        AtLeast("ToolPouchUpgrades", 2.0),
+    ),
+    check(
+        "tool_upgrade.tool_pouch_3",
+        "[Tool Pouch] #3",
+        AtLeast("ToolPouchUpgrades", 3.0),
+    ),
+    check(
+        "tool_upgrade.tool_pouch_4",
+        "[Tool Pouch] #4",
+        AtLeast("ToolPouchUpgrades", 4.0),
+    ),
+    check(
+        "tool_upgrade.crafting_kit_1",
+        "[Crafting Kit] #1",
+        AtLeast("ToolKitUpgrades", 1.0),
+    ),
+    check(
+        "tool_upgrade.crafting_kit_2",
+        "[Crafting Kit] #2",
+        AtLeast("ToolKitUpgrades", 2.0),
+    ),
+    check(
+        "tool_upgrade.crafting_kit_3",
+        "[Crafting Kit] #3",
+        AtLeast("ToolKitUpgrades", 3.0),
+    ),
+    check(
+        "tool_upgrade.crafting_kit_4",
+        "[Crafting Kit] #4",
+        AtLeast("ToolKitUpgrades", 4.0),
+    ),
+];
+
+const MASK_SHARDS: &[CheckDef] = &[
+    check("mask_shard.1", "[Mask Shard] #1", mask_shards(1.0)),
+    check("mask_shard.2", "[Mask Shard] #2", mask_shards(2.0)),
+    check("mask_shard.3", "[Mask Shard] #3", mask_shards(3.0)),
+    check("mask_shard.4", "[Mask Shard] #4", mask_shards(4.0)),
+    check("mask_shard.5", "[Mask Shard] #5", mask_shards(5.0)),
+    check("mask_shard.6", "[Mask Shard] #6", mask_shards(6.0)),
+    check("mask_shard.7", "[Mask Shard] #7", mask_shards(7.0)),
+    check("mask_shard.8", "[Mask Shard] #8", mask_shards(8.0)),
+    check("mask_shard.9", "[Mask Shard] #9", mask_shards(9.0)),
+    check("mask_shard.10", "[Mask Shard] #10", mask_shards(10.0)),
+    check("mask_shard.11", "[Mask Shard] #11", mask_shards(11.0)),
+    check("mask_shard.12", "[Mask Shard] #12", mask_shards(12.0)),
+    check("mask_shard.13", "[Mask Shard] #13", mask_shards(13.0)),
+    check("mask_shard.14", "[Mask Shard] #14", mask_shards(14.0)),
+    check("mask_shard.15", "[Mask Shard] #15", mask_shards(15.0)),
+    check("mask_shard.16", "[Mask Shard] #16", mask_shards(16.0)),
+    check("mask_shard.17", "[Mask Shard] #17", mask_shards(17.0)),
+    check("mask_shard.18", "[Mask Shard] #18", mask_shards(18.0)),
+    check("mask_shard.19", "[Mask Shard] #19", mask_shards(19.0)),
+    check("mask_shard.20", "[Mask Shard] #20", mask_shards(20.0)),
+];
+
+const SPOOL_FRAGMENTS: &[CheckDef] = &[
+    check("spool_fragment.1", "[Spool Fragment] #1", spool_parts(1.0)),
+    check("spool_fragment.2", "[Spool Fragment] #2", spool_parts(2.0)),
+    check("spool_fragment.3", "[Spool Fragment] #3", spool_parts(3.0)),
+    check("spool_fragment.4", "[Spool Fragment] #4", spool_parts(4.0)),
+    check("spool_fragment.5", "[Spool Fragment] #5", spool_parts(5.0)),
+    check("spool_fragment.6", "[Spool Fragment] #6", spool_parts(6.0)),
+    check("spool_fragment.7", "[Spool Fragment] #7", spool_parts(7.0)),
+    check("spool_fragment.8", "[Spool Fragment] #8", spool_parts(8.0)),
+    check("spool_fragment.9", "[Spool Fragment] #9", spool_parts(9.0)),
+    check(
+        "spool_fragment.10",
+        "[Spool Fragment] #10",
+        spool_parts(10.0),
+    ),
+    check(
+        "spool_fragment.11",
+        "[Spool Fragment] #11",
+        spool_parts(11.0),
+    ),
+    check(
+        "spool_fragment.12",
+        "[Spool Fragment] #12",
+        spool_parts(12.0),
+    ),
+    check(
+        "spool_fragment.13",
+        "[Spool Fragment] #13",
+        spool_parts(13.0),
+    ),
+    check(
+        "spool_fragment.14",
+        "[Spool Fragment] #14",
+        spool_parts(14.0),
+    ),
+    check(
+        "spool_fragment.15",
+        "[Spool Fragment] #15",
+        spool_parts(15.0),
+    ),
+    check(
+        "spool_fragment.16",
+        "[Spool Fragment] #16",
+        spool_parts(16.0),
+    ),
+    check(
+        "spool_fragment.17",
+        "[Spool Fragment] #17",
+        spool_parts(17.0),
+    ),
+    check(
+        "spool_fragment.18",
+        "[Spool Fragment] #18",
+        spool_parts(18.0),
+    ),
+];
+
+const SILK_HEARTS: &[CheckDef] = &[
+    check(
+        "silk_heart.1",
+        "[Silk Heart] #1",
+        AtLeast("silkRegenMax", 1.0),
+    ),
+    check(
+        "silk_heart.2",
+        "[Silk Heart] #2",
+        AtLeast("silkRegenMax", 2.0),
+    ),
+    check(
+        "silk_heart.3",
+        "[Silk Heart] #3",
+        AtLeast("silkRegenMax", 3.0),
+    ),
+];
+
+/// A Silksong save, as far as this crate understands it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveFile {
+    pub player_data: Box<PlayerData>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl SaveFile {
+    pub(crate) fn checks(&self) -> SilksongChecks {
+        self.evaluate(&serde_json::to_value(&self.player_data).unwrap_or_default())
+    }
+
+    /// [`Self::checks`], with `playerData` already serialized.
+    pub(crate) fn evaluate(&self, player_data: &Value) -> SilksongChecks {
+        let mut checks = Checklist::evaluate(CHECKS, player_data, |_, _| false);
+        checks.fill(
+            "quests",
+            "quest",
+            self.player_data
+                .quest_completion_data
+                .iter()
+                .map(|(name, quest)| (name.clone(), name.clone(), quest.was_ever_completed)),
+        );
+        checks
+    }
+}
+
+// Silksong saves are still being reverse-engineered, so every field is
+// defaulted: a missing key reads as "not obtained" instead of failing the
+// whole save.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PlayerData {
+    pub heart_pieces: i32,
+    pub max_health_base: i32,
+    pub silk_spool_parts: i32,
+    pub silk_max: i32,
+    pub silk_regen_max: i32,
+    pub nail_upgrades: i32,
+    #[serde(rename = "ToolPouchUpgrades")]
+    pub tool_pouch_upgrades: i32,
+    #[serde(rename = "ToolKitUpgrades")]
+    pub tool_kit_upgrades: i32,
+    pub has_dash: bool,
+    pub has_walljump: bool,
+    pub has_needolin: bool,
+    pub has_harpoon_dash: bool,
+    pub has_charge_slash: bool,
+    pub has_brolly: bool,
+    pub has_double_jump: bool,
+    pub has_super_jump: bool,
+    pub has_needolin_memory_powerup: bool,
+    pub defeated_moss_mother: bool,
+    pub defeated_bell_beast: bool,
+    pub defeated_lace1: bool,
+    pub defeated_song_golem: bool,
+    pub defeated_bone_flyer_giant: bool,
+    pub defeated_splinter_queen: bool,
+    pub skull_king_defeated: bool,
+    pub spinner_defeated: bool,
+    pub defeated_vampire_gnat_boss: bool,
+    pub defeated_phantom: bool,
+    pub defeated_last_judge: bool,
+    pub defeated_cogwork_dancers: bool,
+    pub defeated_trobbio: bool,
+    pub defeated_dock_foremen: bool,
+    pub defeated_roachkeeper_chef: bool,
+    pub defeated_wisp_pyre_effigy: bool,
+    pub defeated_swamp_shaman: bool,
+    pub defeated_coral_driller_solo: bool,
+    pub defeated_lace_tower: bool,
+    #[serde(rename = "Tools")]
+    pub tools: SavedData<ToolData>,
+    #[serde(rename = "ToolEquips")]
+    pub tool_equips: SavedData<CrestData>,
+    #[serde(rename = "QuestCompletionData")]
+    pub quest_completion_data: SavedData<QuestData>,
+    /// Every key this model doesn't know about, kept so that re-encoding
+    /// loses nothing.
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// Unity-serialized dictionary: `{ "savedData": [{ "Name": .., "Data": .. }] }`,
+/// in save order.
+#[derive(Debug, Clone)]
+pub struct SavedData<T>(IndexMap<String, T>);
+
+impl<T> Default for SavedData<T> {
+    fn default() -> Self {
+        Self(IndexMap::new())
+    }
+}
+
+impl<T> std::ops::Deref for SavedData<T> {
+    type Target = IndexMap<String, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Serialize> Serialize for SavedData<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Entry<'a, T> {
+            #[serde(rename = "Name")]
+            name: &'a str,
+            #[serde(rename = "Data")]
+            data: &'a T,
+        }
+
+        #[derive(Serialize)]
+        struct List<'a, T> {
+            #[serde(rename = "savedData")]
+            saved_data: Vec<Entry<'a, T>>,
+        }
+
+        List {
+            saved_data: self
+                .0
+                .iter()
+                .map(|(name, data)| Entry { name, data })
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SavedData<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Entry<T> {
+            #[serde(rename = "Name")]
+            name: String,
+            #[serde(rename = "Data")]
+            data: T,
+        }
+
+        #[derive(Deserialize)]
+        struct List<T> {
+            #[serde(rename = "savedData", default = "Vec::new")]
+            saved_data: Vec<Entry<T>>,
+        }
+
+        let list = List::deserialize(deserializer)?;
+        Ok(Self(
+            list.saved_data
+                .into_iter()
+                .map(|e| (e.name, e.data))
+                .collect(),
+        ))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct ToolData {
+    #[serde(rename = "IsUnlocked")]
+    pub is_unlocked: bool,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct CrestData {
+    #[serde(rename = "IsUnlocked")]
+    pub is_unlocked: bool,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct QuestData {
+    #[serde(rename = "WasEverCompleted")]
+    pub was_ever_completed: bool,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
@@ -0,0 +1,79 @@
+use serde::Serialize;
+
+use crate::hollow_knight::{JournalEntry, SceneData, scene_area};
+
+/// Numbers about a save that aren't checks, only available for Hollow
+/// Knight saves.
+#[derive(Serialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Statistics {
+    /// Geo rocks by area, in the order areas were first seen in the save.
+    pub geo_rocks: Vec<AreaGeoRocks>,
+    /// Every Hunter's Journal entry, the Hunter's Mark needs all of them
+    /// completed.
+    pub hunters_journal: Vec<JournalEntry>,
+    /// Progress of the achievements that count something, whether they're
+    /// obtained is in the checklist's `achievements` category.
+    pub achievements: Vec<AchievementProgress>,
+}
+
+/// How far along an achievement that counts something is.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementProgress {
+    /// Check id of the achievement, like `achievement.blessed`.
+    pub id: &'static str,
+    pub done: i32,
+    pub total: i32,
+}
+
+/// Geo rocks of a single area. Only rocks in scenes the player has been to
+/// are saved, so `found` grows as the map is explored.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AreaGeoRocks {
+    pub area: &'static str,
+    pub broken: usize,
+    pub found: usize,
+    /// Hits left on the rocks that aren't broken yet. The save doesn't say
+    /// how much geo a hit drops, so this is as close to "geo left in the
+    /// world" as it gets.
+    pub hits_left: i32,
+}
+
+impl Statistics {
+    pub(crate) fn new(
+        scene_data: &SceneData,
+        hunters_journal: Vec<JournalEntry>,
+        achievements: Vec<AchievementProgress>,
+    ) -> Self {
+        let mut geo_rocks: Vec<AreaGeoRocks> = Vec::new();
+        for rock in &scene_data.geo_rocks {
+            let area = scene_area(&rock.scene_name);
+            let index = geo_rocks
+                .iter()
+                .position(|a| a.area == area)
+                .unwrap_or_else(|| {
+                    geo_rocks.push(AreaGeoRocks {
+                        area,
+                        broken: 0,
+                        found: 0,
+                        hits_left: 0,
+                    });
+                    geo_rocks.len() - 1
+                });
+            let stats = &mut geo_rocks[index];
+            stats.found += 1;
+            if rock.is_broken() {
+                stats.broken += 1;
+            } else {
+                stats.hits_left += rock.hits_left;
+            }
+        }
+        Self {
+            geo_rocks,
+            hunters_journal,
+            achievements,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::{HOLLOW_KNIGHT as SAVE, with_fields};
+use hollow_knight_save_parser::{GameSer, Parser, decrypt_to_json};
+use serde_json::{Value, json};
+
+/// Ids of the achievements obtained in the fixture with some `playerData`
+/// fields replaced.
+fn obtained(fields: &Value) -> Vec<String> {
+    let mut parser = Parser::new();
+    parser.parse_save_file(&with_fields(SAVE, fields)).unwrap();
+    let GameSer::HollowKnight(checks) = parser.get_map() else {
+        panic!("not a Hollow Knight save");
+    };
+    checks
+        .category("achievements")
+        .unwrap()
+        .iter()
+        .filter(|(_, check)| check.checked)
+        .map(|(id, _)| id.clone())
+        .collect()
+}
+
+fn has(fields: &Value, id: &str) -> bool {
+    obtained(fields).iter().any(|obtained| obtained == id)
+}
+
+#[test]
+fn colosseum_trials() {
+    let fields = json!({
+        "colosseumBronzeCompleted": true,
+        "colosseumSilverCompleted": true,
+        "colosseumGoldCompleted": false,
+    });
+    assert!(has(&fields, "achievement.warrior"));
+    assert!(has(&fields, "achievement.conqueror"));
+    assert!(!has(&fields, "achievement.fool"));
+}
+
+#[test]
+fn seer_gifts() {
+    let none = json!({ "dreamOrbs": 599, "dreamNailUpgraded": false, "mothDeparted": false });
+    assert!(!has(&none, "achievement.attunement"));
+    let essence = json!({ "dreamOrbs": 600, "dreamNailUpgraded": false, "mothDeparted": false });
+    assert!(has(&essence, "achievement.attunement"));
+    assert!(!has(&essence, "achievement.awakening"));
+    let ascended = json!({ "dreamOrbs": 0, "dreamNailUpgraded": true, "mothDeparted": true });
+    assert!(has(&ascended, "achievement.attunement"));
+    assert!(has(&ascended, "achievement.awakening"));
+    assert!(has(&ascended, "achievement.ascension"));
+}
+
+#[test]
+fn sealed_siblings_needs_the_void_heart() {
+    let ending = json!({ "killedHollowKnight": true, "gotShadeCharm": false });
+    assert!(has(&ending, "achievement.hollow_knight"));
+    assert!(!has(&ending, "achievement.sealed_siblings"));
+    let void_heart = json!({ "killedHollowKnight": true, "gotShadeCharm": true });
+    assert!(has(&void_heart, "achievement.sealed_siblings"));
+}
+
+#[test]
+fn keen_hunter_needs_every_entry_discovered() {
+    let json = decrypt_to_json(SAVE).unwrap();
+    let enemies: Vec<String> = json["playerData"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .filter_map(|key| key.strip_prefix("newData"))
+        .map(str::to_owned)
+        .collect();
+    assert!(!enemies.is_empty());
+
+    let mut fields = json!({ "hasHuntersMark": false });
+    for enemy in &enemies {
+        fields[format!("killed{enemy}")] = json!(true);
+    }
+    assert!(has(&fields, "achievement.keen_hunter"));
+    assert!(!has(&fields, "achievement.true_hunter"));
+
+    fields[format!("killed{}", enemies[0])] = json!(false);
+    assert!(!has(&fields, "achievement.keen_hunter"));
+}
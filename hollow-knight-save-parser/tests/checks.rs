@@ -0,0 +1,80 @@
+//! Predicates look `playerData` fields up by name, so a misspelled path would
+//! silently read as unchecked. Every path has to be a field of the typed
+//! model, which is what the parser requires real saves to have.
+
+use hollow_knight_save_parser::{
+    CategoryDef, HOLLOW_KNIGHT_CHECKS, Parser, Predicate, SILKSONG_CHECKS, SaveData,
+};
+use serde_json::Value;
+
+/// Every `playerData` path a predicate reads.
+fn paths(predicate: &Predicate, out: &mut Vec<&'static str>) {
+    match *predicate {
+        Predicate::Flag(path)
+        | Predicate::AtLeast(path, _)
+        | Predicate::Below(path, _)
+        | Predicate::Equals(path, _)
+        | Predicate::Unlocked(path, _) => out.push(path),
+        Predicate::Pieces { pieces, whole, .. } => out.extend([pieces, whole]),
+        Predicate::Scene(..) | Predicate::Derived => {}
+        Predicate::All(predicates) | Predicate::Any(predicates) => {
+            predicates.iter().for_each(|p| paths(p, out));
+        }
+    }
+}
+
+/// `(check id, path)` for every path that isn't a field of `player_data`.
+fn unknown_paths(categories: &[CategoryDef], player_data: &Value) -> Vec<(&'static str, String)> {
+    let mut unknown = Vec::new();
+    for check in categories.iter().flat_map(|category| category.checks) {
+        let mut found = Vec::new();
+        paths(&check.predicate, &mut found);
+        if let Some(counted_as) = &check.counted_as {
+            paths(counted_as, &mut found);
+        }
+        for path in found {
+            if path
+                .split('.')
+                .try_fold(player_data, |v, key| v.get(key))
+                .is_none()
+            {
+                unknown.push((check.id, path.to_owned()));
+            }
+        }
+    }
+    unknown
+}
+
+fn save(data: &[u8]) -> SaveData {
+    let mut parser = Parser::new();
+    parser.parse_save_file(data).unwrap();
+    parser.get_save_data().unwrap()
+}
+
+#[test]
+fn hollow_knight_paths_are_modeled() {
+    let SaveData::HollowKnight(mut save) = save(include_bytes!("saves/hollow_knight.dat")) else {
+        panic!("not a Hollow Knight save");
+    };
+    save.player_data.other.clear();
+    let player_data = serde_json::to_value(&save.player_data).unwrap();
+    assert_eq!(
+        unknown_paths(HOLLOW_KNIGHT_CHECKS, &player_data),
+        [],
+        "add these fields to `hollow_knight::PlayerData`"
+    );
+}
+
+#[test]
+fn silksong_paths_are_modeled() {
+    let SaveData::Silksong(mut save) = save(include_bytes!("saves/silksong.dat")) else {
+        panic!("not a Silksong save");
+    };
+    save.player_data.other.clear();
+    let player_data = serde_json::to_value(&save.player_data).unwrap();
+    assert_eq!(
+        unknown_paths(SILKSONG_CHECKS, &player_data),
+        [],
+        "add these fields to `silksong::PlayerData`"
+    );
+}
@@ -0,0 +1,94 @@
+//! The fixtures in `tests/saves` were written by this crate, so they only
+//! show that it reads back what it wrote. Point `HKSAVE_SAVES` at a folder of
+//! `user<N>.dat` files written by the games, like the game's own save folder,
+//! to run every test here on those as well.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use hollow_knight_save_parser::{Parser, SaveEditor, decrypt_save_file, encode_save_file};
+
+/// Every `*.dat` in `dir`.
+fn saves_in(dir: &Path) -> Vec<(PathBuf, Vec<u8>)> {
+    let mut saves: Vec<_> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("can't read {}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "dat"))
+        .map(|path| {
+            let data = fs::read(&path).unwrap();
+            (path, data)
+        })
+        .collect();
+    saves.sort();
+    saves
+}
+
+/// Saves the games wrote, from `HKSAVE_SAVES`.
+fn game_saves() -> Vec<(PathBuf, Vec<u8>)> {
+    let Some(dir) = env::var_os("HKSAVE_SAVES") else {
+        eprintln!("HKSAVE_SAVES isn't set, only testing the fixtures");
+        return Vec::new();
+    };
+    let saves = saves_in(Path::new(&dir));
+    assert!(!saves.is_empty(), "no saves in HKSAVE_SAVES");
+    saves
+}
+
+/// Every `tests/saves/*.dat`, so new saves are covered by dropping them in,
+/// and the game saves.
+fn saves() -> Vec<(PathBuf, Vec<u8>)> {
+    let mut saves = saves_in(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/saves"));
+    assert!(!saves.is_empty(), "no saves in tests/saves");
+    saves.extend(game_saves());
+    saves
+}
+
+#[test]
+fn encode_reverses_decode_byte_for_byte() {
+    for (path, data) in saves() {
+        let json = decrypt_save_file(&data).unwrap();
+        assert!(
+            encode_save_file(&json) == data,
+            "{} changed after decoding and encoding it",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn decode_reverses_encode() {
+    let mut documents: Vec<Vec<u8>> = saves()
+        .iter()
+        .map(|(_, data)| decrypt_save_file(data).unwrap())
+        .collect();
+    // Padding a whole extra block, and length headers of one to three bytes
+    for len in [0, 1, 15, 16, 17, 95, 96, 97, 12_000, 100_000] {
+        documents.push(format!("{{\"a\":\"{}\"}}", "x".repeat(len)).into_bytes());
+    }
+
+    for json in documents {
+        assert!(
+            decrypt_save_file(&encode_save_file(&json)).unwrap() == json,
+            "{} byte document changed after encoding and decoding it",
+            json.len()
+        );
+    }
+}
+
+#[test]
+fn game_saves_parse_and_are_written_back_unchanged() {
+    for (path, data) in game_saves() {
+        let mut parser = Parser::new();
+        if let Err(e) = parser.parse_save_file(&data) {
+            panic!("{}: {e}", path.display());
+        }
+        let editor = SaveEditor::from_save_file(&data).unwrap();
+        assert!(
+            editor.to_save_file() == data,
+            "{} changed after loading it into the editor",
+            path.display()
+        );
+    }
+}
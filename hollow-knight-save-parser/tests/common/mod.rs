@@ -0,0 +1,43 @@
+//! Fixture factories shared by the tests. Not every test uses all of them.
+#![allow(dead_code)]
+
+use hollow_knight_save_parser::{decrypt_to_json, encode_save_file};
+use serde_json::{Value, json};
+
+pub const HOLLOW_KNIGHT: &[u8] = include_bytes!("../saves/hollow_knight.dat");
+pub const SILKSONG: &[u8] = include_bytes!("../saves/silksong.dat");
+
+/// Encrypts save JSON into a `user<N>.dat` file.
+pub fn encode(json: &Value) -> Vec<u8> {
+    encode_save_file(&serde_json::to_vec(json).unwrap())
+}
+
+/// `save` with some `playerData` fields replaced.
+pub fn with_fields(save: &[u8], fields: &Value) -> Vec<u8> {
+    let mut json = decrypt_to_json(save).unwrap();
+    for (key, value) in fields.as_object().unwrap() {
+        json["playerData"][key] = value.clone();
+    }
+    encode(&json)
+}
+
+/// The Hollow Knight fixture's JSON with nothing obtained: every flag off,
+/// every number zero, the knight's starting five masks and no charms on.
+pub fn new_game() -> Value {
+    fn reset(value: &mut Value) {
+        match value {
+            Value::Bool(b) => *b = false,
+            Value::Number(_) => *value = json!(0),
+            Value::Object(fields) => fields.values_mut().for_each(reset),
+            _ => {}
+        }
+    }
+
+    let mut json = decrypt_to_json(HOLLOW_KNIGHT).unwrap();
+    let player_data = &mut json["playerData"];
+    reset(player_data);
+    player_data["maxHealthBase"] = json!(5);
+    player_data["maxHealth"] = json!(5);
+    player_data["equippedCharms"] = json!([]);
+    json
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::{encode, new_game};
+use hollow_knight_save_parser::{Completion, Parser};
+use serde_json::{Value, json};
+
+fn completion(json: &Value) -> Completion {
+    let mut parser = Parser::new();
+    parser.parse_save_file(&encode(json)).unwrap();
+    parser.get_completion().unwrap()
+}
+
+fn category(completion: &Completion, name: &str) -> f64 {
+    completion
+        .categories
+        .iter()
+        .find(|c| c.category == name)
+        .unwrap()
+        .percent
+}
+
+#[test]
+fn new_game_is_zero_percent() {
+    let completion = completion(&new_game());
+    assert_eq!(completion.percent, 0.0);
+    assert_eq!(completion.discrepancy, 0.0);
+}
+
+#[test]
+fn consistent_save_has_no_discrepancy() {
+    let mut json = new_game();
+    let player_data = &mut json["playerData"];
+    for (field, value) in [
+        // 2% each
+        ("hasDash", json!(true)),
+        ("hasWalljump", json!(true)),
+        // 1% each
+        ("gotCharm_2", json!(true)),
+        ("gotCharm_36", json!(true)),
+        ("charmsOwned", json!(2)),
+        ("falseKnightDefeated", json!(true)),
+        // Vengeful Spirit and Shade Soul
+        ("fireballLevel", json!(2)),
+        ("maxHealthBase", json!(6)),
+        ("heartPieces", json!(1)),
+        ("MPReserveMax", json!(33)),
+        ("nailSmithUpgrades", json!(1)),
+        ("dreamNailUpgraded", json!(true)),
+        ("completionPercentage", json!(13)),
+    ] {
+        player_data[field] = value;
+    }
+
+    let completion = completion(&json);
+    assert_eq!(completion.percent, 13.0);
+    assert_eq!(completion.discrepancy, 0.0);
+}
+
+#[test]
+fn kingsoul_counts_from_the_white_fragment() {
+    let mut json = new_game();
+    json["playerData"]["gotCharm_36"] = json!(true);
+    json["playerData"]["gotQueenFragment"] = json!(true);
+    assert_eq!(category(&completion(&json), "charms"), 1.0);
+}
+
+#[test]
+fn masks_and_vessels_count_from_max_health_and_soul() {
+    // No shard or fragment locations, as if bought or edited in
+    let mut json = new_game();
+    json["playerData"]["maxHealthBase"] = json!(7);
+    json["playerData"]["heartPieces"] = json!(3);
+    json["playerData"]["MPReserveMax"] = json!(66);
+    json["playerData"]["vesselFragments"] = json!(2);
+    let completion = completion(&json);
+    assert_eq!(category(&completion, "maskShards"), 2.0);
+    assert_eq!(category(&completion, "vesselFragments"), 2.0);
+}
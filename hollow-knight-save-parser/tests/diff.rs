@@ -0,0 +1,105 @@
+mod common;
+
+use common::{HOLLOW_KNIGHT, SILKSONG, encode, new_game};
+use hollow_knight_save_parser::{
+    CheckChange, FieldChange, Parser, SaveData, SaveDiff, SceneItem, diff_saves,
+};
+use serde_json::{Value, json};
+
+fn save(data: &[u8]) -> SaveData {
+    let mut parser = Parser::new();
+    parser.parse_save_file(data).unwrap();
+    parser.get_save_data().unwrap()
+}
+
+fn diff_json(old: &Value, new: &Value) -> SaveDiff {
+    diff_saves(&save(&encode(old)), &save(&encode(new))).unwrap()
+}
+
+/// Diff of a new game against the same new game after `edit`.
+fn diff(edit: impl FnOnce(&mut Value)) -> SaveDiff {
+    let old = new_game();
+    let mut new = old.clone();
+    edit(&mut new);
+    diff_json(&old, &new)
+}
+
+#[test]
+fn same_save_has_no_changes() {
+    let diff = diff(|_| {});
+    assert!(diff.is_empty(), "{diff:?}");
+}
+
+#[test]
+fn checks_flip_both_ways() {
+    let before = new_game();
+    let mut after = before.clone();
+    after["playerData"]["hasDash"] = json!(true);
+    let change = |checked| CheckChange {
+        id: "equipment.mothwing_cloak".to_owned(),
+        label: "Mothwing Cloak".to_owned(),
+        checked,
+    };
+    assert_eq!(
+        diff_json(&before, &after).checks["equipment"],
+        [change(true)]
+    );
+    assert_eq!(
+        diff_json(&after, &before).checks["equipment"],
+        [change(false)]
+    );
+}
+
+#[test]
+fn changed_fields_by_path() {
+    let diff = diff(|json| {
+        json["playerData"]["geo"] = json!(120);
+        json["playerData"]["bossDoorStateTier1"]["allPlayed"] = json!(true);
+    });
+    assert_eq!(
+        diff.player_data,
+        [
+            FieldChange {
+                path: "bossDoorStateTier1.allPlayed".to_owned(),
+                old: json!(false),
+                new: json!(true),
+            },
+            FieldChange {
+                path: "geo".to_owned(),
+                old: json!(0),
+                new: json!(120),
+            },
+        ]
+    );
+    assert!(diff.checks.is_empty(), "{:?}", diff.checks);
+}
+
+#[test]
+fn newly_activated_scene_items() {
+    let diff = diff(|json| {
+        let items = json["sceneData"]["persistentBoolItems"]
+            .as_array_mut()
+            .unwrap();
+        let item = items
+            .iter_mut()
+            .find(|item| item["sceneName"] == "Crossroads_09" && item["id"] == "Heart Piece")
+            .unwrap();
+        item["activated"] = json!(true);
+    });
+    assert_eq!(
+        diff.activated,
+        [SceneItem {
+            scene_name: "Crossroads_09".to_owned(),
+            id: "Heart Piece".to_owned(),
+        }]
+    );
+    assert!(diff.player_data.is_empty(), "{:?}", diff.player_data);
+}
+
+#[test]
+fn saves_of_different_games_arent_compared() {
+    let hollow_knight = save(HOLLOW_KNIGHT);
+    let silksong = save(SILKSONG);
+    assert_eq!(diff_saves(&hollow_knight, &silksong), None);
+    assert_eq!(diff_saves(&silksong, &hollow_knight), None);
+}
@@ -0,0 +1,187 @@
+mod common;
+
+use common::{HOLLOW_KNIGHT, SILKSONG, encode, new_game};
+use hollow_knight_save_parser::{
+    EditError, GameSer, HOLLOW_KNIGHT_CHECKS, Parser, SILKSONG_CHECKS, SaveEditor, decrypt_to_json,
+};
+use serde_json::{Value, json};
+
+/// Whether the check is checked once the edited save is read back.
+fn checked(editor: &SaveEditor, category: &str, id: &str) -> bool {
+    let mut parser = Parser::new();
+    parser.parse_save_file(&editor.to_save_file()).unwrap();
+    let checks = match parser.get_map() {
+        GameSer::HollowKnight(checks) => checks,
+        GameSer::Silksong(checks) => checks,
+    };
+    checks.category(category).unwrap()[id].checked
+}
+
+fn number(editor: &SaveEditor, path: &str) -> i64 {
+    editor
+        .player_data(path)
+        .as_ref()
+        .and_then(Value::as_i64)
+        .unwrap()
+}
+
+/// Ids of a Hollow Knight category's checks.
+fn ids(category: &str) -> Vec<&'static str> {
+    let category = HOLLOW_KNIGHT_CHECKS.iter().find(|c| c.name == category);
+    category.unwrap().checks.iter().map(|c| c.id).collect()
+}
+
+/// A new game with nothing in `category`'s scenes either.
+fn without(category: &str) -> SaveEditor {
+    let mut editor = SaveEditor::from_save_file(&encode(&new_game())).unwrap();
+    for id in ids(category) {
+        editor.revoke_check(id).unwrap();
+    }
+    editor
+}
+
+#[test]
+fn every_check_grants_and_revokes() {
+    for (save, categories) in [
+        (HOLLOW_KNIGHT, HOLLOW_KNIGHT_CHECKS),
+        (SILKSONG, SILKSONG_CHECKS),
+    ] {
+        for category in categories.iter().filter(|c| !c.derived) {
+            for check in category.checks {
+                let mut editor = SaveEditor::from_save_file(save).unwrap();
+                editor.grant_check(check.id).unwrap();
+                assert!(checked(&editor, category.name, check.id), "{}", check.id);
+                editor.revoke_check(check.id).unwrap();
+                assert!(!checked(&editor, category.name, check.id), "{}", check.id);
+            }
+        }
+    }
+}
+
+#[test]
+fn derived_checks_arent_granted() {
+    let mut editor = SaveEditor::from_save_file(HOLLOW_KNIGHT).unwrap();
+    let before = editor.to_json();
+    assert!(matches!(
+        editor.grant_check("achievement.blessed"),
+        Err(EditError::Unsupported(_))
+    ));
+    assert!(matches!(
+        editor.grant_check("journal.buzzer"),
+        Err(EditError::UnknownCheck(_))
+    ));
+    assert!(editor.to_json() == before, "a failed edit changed the save");
+}
+
+#[test]
+fn mask_shards_fill_masks() {
+    let mut editor = without("maskShards");
+    assert_eq!(number(&editor, "maxHealthBase"), 5);
+    assert_eq!(number(&editor, "heartPieces"), 0);
+
+    let shards = ids("maskShards");
+    for id in &shards[..3] {
+        editor.grant_check(id).unwrap();
+    }
+    assert_eq!(number(&editor, "heartPieces"), 3);
+    assert_eq!(number(&editor, "maxHealth"), 5);
+
+    editor.grant_check(shards[3]).unwrap();
+    assert_eq!(number(&editor, "heartPieces"), 0);
+    assert_eq!(number(&editor, "maxHealthBase"), 6);
+    assert_eq!(number(&editor, "maxHealth"), 6);
+
+    editor.revoke_check(shards[0]).unwrap();
+    assert_eq!(number(&editor, "heartPieces"), 3);
+    assert_eq!(number(&editor, "maxHealthBase"), 5);
+    assert_eq!(number(&editor, "maxHealth"), 5);
+}
+
+#[test]
+fn vessel_fragments_fill_vessels() {
+    let mut editor = without("vesselFragments");
+    assert_eq!(number(&editor, "MPReserveMax"), 0);
+    assert_eq!(number(&editor, "vesselFragments"), 0);
+
+    let fragments = ids("vesselFragments");
+    for id in &fragments[..2] {
+        editor.grant_check(id).unwrap();
+    }
+    assert_eq!(number(&editor, "vesselFragments"), 2);
+    assert_eq!(number(&editor, "MPReserveMax"), 0);
+
+    editor.grant_check(fragments[2]).unwrap();
+    assert_eq!(number(&editor, "vesselFragments"), 0);
+    assert_eq!(number(&editor, "MPReserveMax"), 33);
+
+    editor.revoke_check(fragments[1]).unwrap();
+    assert_eq!(number(&editor, "vesselFragments"), 2);
+    assert_eq!(number(&editor, "MPReserveMax"), 0);
+}
+
+#[test]
+fn grubs_are_counted() {
+    let mut editor = without("grubs");
+    assert_eq!(number(&editor, "grubsCollected"), 0);
+    for id in &ids("grubs")[..2] {
+        editor.grant_check(id).unwrap();
+    }
+    assert_eq!(number(&editor, "grubsCollected"), 2);
+    editor.revoke_check(ids("grubs")[0]).unwrap();
+    assert_eq!(number(&editor, "grubsCollected"), 1);
+}
+
+#[test]
+fn dream_warriors_count_only_at_two() {
+    let mut editor = without("dreamWarriors");
+    editor.grant_check("dream_warrior.xero").unwrap();
+    assert_eq!(number(&editor, "xeroDefeated"), 2);
+    editor.revoke_check("dream_warrior.xero").unwrap();
+    assert_eq!(number(&editor, "xeroDefeated"), 1);
+    // The game only ever writes 0, 1 or 2
+    editor.set_player_data("xeroDefeated", json!(3)).unwrap();
+    assert!(!checked(&editor, "dreamWarriors", "dream_warrior.xero"));
+}
+
+#[test]
+fn charms_are_counted_and_taken_off() {
+    let mut editor = without("charms");
+    assert_eq!(number(&editor, "charmsOwned"), 0);
+    assert_eq!(editor.player_data("equippedCharms"), Some(json!([])));
+
+    editor.grant_check("charm.wayward_compass").unwrap();
+    editor.grant_check("charm.gathering_swarm").unwrap();
+    assert_eq!(number(&editor, "charmsOwned"), 2);
+
+    editor
+        .set_player_data("equippedCharms", json!([2]))
+        .unwrap();
+    editor.revoke_check("charm.wayward_compass").unwrap();
+    assert_eq!(number(&editor, "charmsOwned"), 1);
+    assert_eq!(editor.player_data("equippedCharms"), Some(json!([])));
+}
+
+#[test]
+fn unedited_saves_are_written_back_unchanged() {
+    for save in [HOLLOW_KNIGHT, SILKSONG] {
+        let editor = SaveEditor::from_save_file(save).unwrap();
+        assert!(editor.to_save_file() == save, "the save changed");
+    }
+}
+
+#[test]
+fn edits_leave_the_rest_as_written() {
+    // Missing fields the model fills in, and floats written without a
+    // fraction like `completionPercentage: 50`, stay that way
+    for (save, path, value) in [
+        (HOLLOW_KNIGHT, "geo", json!(1234)),
+        (SILKSONG, "nailUpgrades", json!(3)),
+    ] {
+        let mut editor = SaveEditor::from_save_file(save).unwrap();
+        editor.set_player_data(path, value.clone()).unwrap();
+        let mut expected = decrypt_to_json(save).unwrap();
+        expected["playerData"][path] = value;
+        let edited: Value = serde_json::from_slice(&editor.to_json()).unwrap();
+        assert_eq!(edited, expected);
+    }
+}
@@ -0,0 +1,55 @@
+use hollow_knight_save_parser::{GameSer, JournalEntry, Parser, SaveData};
+
+const SAVE: &[u8] = include_bytes!("saves/hollow_knight.dat");
+
+fn entry(enemy: &str) -> JournalEntry {
+    JournalEntry {
+        enemy: enemy.to_owned(),
+        discovered: true,
+        completed: false,
+        kills_left: 1,
+    }
+}
+
+#[test]
+fn entries_use_the_journal_names() {
+    assert_eq!(entry("ZombieRunner").label(), "Wandering Husk");
+    assert_eq!(entry("BigBuzzer").label(), "Vengefly King");
+    assert_eq!(entry("Crawler").label(), "Crawlid");
+    // Already the journal name once split
+    assert_eq!(entry("FalseKnight").label(), "False Knight");
+}
+
+#[test]
+fn checks_are_named_after_the_journal() {
+    let mut parser = Parser::new();
+    parser.parse_save_file(SAVE).unwrap();
+    let Some(SaveData::HollowKnight(save)) = parser.get_save_data() else {
+        panic!("not a Hollow Knight save");
+    };
+    let GameSer::HollowKnight(checks) = parser.get_map() else {
+        panic!("not a Hollow Knight save");
+    };
+    let labels: Vec<_> = checks
+        .category("huntersJournal")
+        .unwrap()
+        .values()
+        .map(|check| check.label.clone())
+        .collect();
+    let expected: Vec<_> = save.journal().iter().map(JournalEntry::label).collect();
+    assert_eq!(labels, expected);
+    assert!(labels.contains(&"Vengefly".to_owned()), "{labels:?}");
+}
+
+#[test]
+fn ids_follow_the_save_not_the_label() {
+    let mut parser = Parser::new();
+    parser.parse_save_file(SAVE).unwrap();
+    let GameSer::HollowKnight(checks) = parser.get_map() else {
+        panic!("not a Hollow Knight save");
+    };
+    let journal = checks.category("huntersJournal").unwrap();
+    assert_eq!(journal["journal.big_buzzer"].label, "Vengefly King");
+    assert_eq!(journal["journal.buzzer"].label, "Vengefly");
+    assert_eq!(journal["journal.crawler"].label, "Crawlid");
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::{HOLLOW_KNIGHT as SAVE, encode};
+use hollow_knight_save_parser::{ParseError, Parser, SaveData, decrypt_to_json};
+use serde_json::{Value, json};
+
+/// The fixture without some `playerData` keys.
+fn without(keys: &[&str]) -> Vec<u8> {
+    without_in(decrypt_to_json(SAVE).unwrap(), keys)
+}
+
+fn without_in(mut json: Value, keys: &[&str]) -> Vec<u8> {
+    let player_data = json["playerData"].as_object_mut().unwrap();
+    for key in keys {
+        assert!(player_data.remove(*key).is_some(), "no `{key}` to remove");
+    }
+    encode(&json)
+}
+
+#[test]
+fn later_fields_can_be_missing() {
+    // Not in every patch, or stripped by mods
+    let save = without(&[
+        "charmCost_1",
+        "placedMarkers_r",
+        "journalEntriesTotal",
+        "mapZone",
+    ]);
+    let mut parser = Parser::new();
+    parser.parse_save_file(&save).unwrap();
+    let Some(SaveData::HollowKnight(save)) = parser.get_save_data() else {
+        panic!("not a Hollow Knight save");
+    };
+    assert_eq!(save.player_data.charm_cost_1, 0);
+    assert_eq!(save.player_data.placed_markers_r, []);
+    assert_eq!(save.player_data.journal_entries_total, 0);
+}
+
+#[test]
+fn checklist_fields_cant() {
+    let save = without(&["hasDash"]);
+    assert!(Parser::new().parse_save_file(&save).is_err());
+}
+
+#[test]
+fn saves_before_godmaster_are_unsupported() {
+    let mut json = decrypt_to_json(SAVE).unwrap();
+    json["playerData"]["version"] = json!("1.3.1.5");
+    let save = without_in(json, &["bossDoorStateTier1"]);
+    assert_eq!(
+        Parser::new().parse_save_file(&save),
+        Err(ParseError::UnsupportedVersion("1.3.1.5".to_owned()))
+    );
+}
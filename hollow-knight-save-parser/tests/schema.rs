@@ -0,0 +1,35 @@
+use hollow_knight_save_parser::{Parser, json_schema};
+
+const SCHEMA: &str = include_str!("../schema/checklist.schema.json");
+
+#[test]
+fn schema_is_up_to_date() {
+    let schema: serde_json::Value = serde_json::from_str(SCHEMA).unwrap();
+    assert_eq!(
+        schema,
+        json_schema(),
+        "run `hksave schema --json-schema -o schema/checklist.schema.json`"
+    );
+}
+
+#[test]
+fn parser_output_matches_schema() {
+    let schema: serde_json::Value = serde_json::from_str(SCHEMA).unwrap();
+    let validator = jsonschema::draft202012::new(&schema).unwrap();
+
+    for (name, save) in [
+        (
+            "hollow_knight",
+            &include_bytes!("saves/hollow_knight.dat")[..],
+        ),
+        ("silksong", &include_bytes!("saves/silksong.dat")[..]),
+    ] {
+        let mut parser = Parser::new();
+        parser.parse_save_file(save).unwrap();
+        let map = serde_json::to_value(parser.get_map()).unwrap();
+        assert!(
+            validator.is_valid(&map),
+            "{name}.dat doesn't match the schema"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::{SILKSONG, with_fields};
+use hollow_knight_save_parser::{GameSer, Parser, SaveEditor};
+use serde_json::{Value, json};
+
+/// The fixture with some `playerData` fields replaced.
+fn save_with(fields: &Value) -> Vec<u8> {
+    with_fields(SILKSONG, fields)
+}
+
+/// How many checks of a category are checked.
+fn checked(save: &[u8], category: &str) -> usize {
+    let mut parser = Parser::new();
+    parser.parse_save_file(save).unwrap();
+    let GameSer::Silksong(checks) = parser.get_map() else {
+        panic!("not a Silksong save");
+    };
+    checks
+        .category(category)
+        .unwrap()
+        .values()
+        .filter(|check| check.checked)
+        .count()
+}
+
+#[test]
+fn pieces_restarting_at_every_mask_and_spool() {
+    let save = save_with(&json!({
+        "heartPieces": 2,
+        "maxHealthBase": 7,
+        "silkSpoolParts": 1,
+        "silkMax": 12,
+    }));
+    assert_eq!(checked(&save, "maskShards"), 10);
+    assert_eq!(checked(&save, "spoolFragments"), 7);
+}
+
+#[test]
+fn pieces_counted_as_running_totals() {
+    let save = save_with(&json!({
+        "heartPieces": 10,
+        "maxHealthBase": 7,
+        "silkSpoolParts": 7,
+        "silkMax": 12,
+    }));
+    assert_eq!(checked(&save, "maskShards"), 10);
+    assert_eq!(checked(&save, "spoolFragments"), 7);
+}
+
+#[test]
+fn every_piece_collected() {
+    for fields in [
+        json!({ "heartPieces": 0, "maxHealthBase": 10, "silkSpoolParts": 0, "silkMax": 18 }),
+        json!({ "heartPieces": 20, "maxHealthBase": 10, "silkSpoolParts": 18, "silkMax": 18 }),
+    ] {
+        let save = save_with(&fields);
+        assert_eq!(checked(&save, "maskShards"), 20, "{fields}");
+        assert_eq!(checked(&save, "spoolFragments"), 18, "{fields}");
+    }
+}
+
+#[test]
+fn no_pieces_without_whole_items() {
+    let save = save_with(&json!({ "heartPieces": 0, "silkSpoolParts": 0 }));
+    assert_eq!(checked(&save, "maskShards"), 0);
+    assert_eq!(checked(&save, "spoolFragments"), 0);
+}
+
+#[test]
+fn granting_a_shard_keeps_the_way_the_save_counts() {
+    let mut editor = SaveEditor::from_save_file(&save_with(&json!({
+        "heartPieces": 3,
+        "maxHealthBase": 5,
+    })))
+    .unwrap();
+    editor.grant_check("mask_shard.4").unwrap();
+    assert_eq!(editor.player_data("heartPieces"), Some(json!(0)));
+    assert_eq!(editor.player_data("maxHealthBase"), Some(json!(6)));
+    assert_eq!(checked(&editor.to_save_file(), "maskShards"), 4);
+
+    let mut editor = SaveEditor::from_save_file(&save_with(&json!({
+        "heartPieces": 4,
+        "maxHealthBase": 6,
+    })))
+    .unwrap();
+    editor.grant_check("mask_shard.5").unwrap();
+    assert_eq!(editor.player_data("heartPieces"), Some(json!(5)));
+    assert_eq!(editor.player_data("maxHealthBase"), Some(json!(6)));
+    editor.revoke_check("mask_shard.1").unwrap();
+    assert_eq!(checked(&editor.to_save_file(), "maskShards"), 0);
+}
@@ -1,7 +1,34 @@
 import init, { Parser } from 'hollow-knight-save-parser';
 import { useCallback, useEffect, useState } from 'react';
 
-import { SaveFile } from '../types/checklist';
+import { ParseError, ParseErrorKind, SaveFile } from '../types/checklist';
+import type { GameSer } from '../types/gameSer';
+
+const PARSE_ERROR_MESSAGES: Partial<Record<ParseErrorKind, string>> = {
+    invalidHeader:
+        'This does not look like a save file. Make sure to upload a `user#.dat` file.',
+    invalidTrailingByte: 'The save file is incomplete or corrupted.',
+    badLength: 'The save file is incomplete or corrupted.',
+    base64: 'The save file is corrupted.',
+    decrypt: 'The save file is corrupted.',
+    unknownGame:
+        'The save file is neither a Hollow Knight nor a Silksong save.',
+    unsupportedVersion:
+        'Saves from this game version are not supported. Load and save it in an up to date game first.',
+};
+
+const isParseError = (err: unknown): err is ParseError =>
+    typeof err === 'object' &&
+    err !== null &&
+    'kind' in err &&
+    'message' in err;
+
+const errorMessage = (err: unknown): string => {
+    if (isParseError(err)) {
+        return PARSE_ERROR_MESSAGES[err.kind] ?? err.message;
+    }
+    return err instanceof Error ? err.message : 'Unknown error occurred';
+};
 
 const useSaveParser = () => {
     const [isLoading, setIsLoading] = useState(false);
@@ -37,14 +64,10 @@ const useSaveParser = () => {
                 const bytes = new Uint8Array(await file.arrayBuffer());
                 const parser = new Parser();
                 parser.parse_save_file(bytes);
-                const map = parser.get_map();
+                const map: GameSer = parser.get_map();
                 setResult(map);
             } catch (err) {
-                setError(
-                    err instanceof Error
-                        ? err.message
-                        : 'Unknown error occurred'
-                );
+                setError(errorMessage(err));
                 console.error('Parsing error:', err);
             } finally {
                 setIsLoading(false);
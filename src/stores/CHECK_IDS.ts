@@ -0,0 +1,428 @@
+import { PartialDeep } from 'type-fest';
+
+import { CheckIds } from '../types/checklist';
+
+/**
+ * Planner check of every check id the savefile parser reports, by section.
+ * Ids stay the same when labels are reworded on either side.
+ */
+const HOLLOW_KNIGHT_CHECK_IDS: CheckIds<'hollow-knight'> = {
+    bosses: {
+        'boss.broken_vessel': '[Broken Vessel]',
+        'boss.brooding_mawlek': '[Brooding Mawlek]',
+        'boss.the_collector': '[The Collector]',
+        'boss.dung_defender': '[Dung Defender]',
+        'boss.false_knight': '[False Knight]',
+        'boss.grimm': '[Grimm]',
+        'boss.gruz_mother': '[Gruz Mother]',
+        'boss.hive_knight': '[Hive Knight]',
+        'boss.hornet_protector': '[Hornet Protector]',
+        'boss.hornet_sentinel': '[Hornet Sentinel]',
+        'boss.mantis_lords': '[Mantis Lords]',
+        'boss.nosk': '[Nosk]',
+        'boss.soul_master': '[Soul Master]',
+        'boss.traitor_lord': '[Traitor Lord]',
+        'boss.uumuu': '[Uumuu]',
+        'boss.watcher_knight': '[Watcher Knight]',
+    },
+    charms: {
+        'charm.wayward_compass': '[Wayward Compass]',
+        'charm.gathering_swarm': '[Gathering Swarm]',
+        'charm.stalwart_shell': '[Stalwart Shell]',
+        'charm.soul_catcher': '[Soul Catcher]',
+        'charm.shaman_stone': '[Shaman Stone]',
+        'charm.soul_eater': '[Soul Eater]',
+        'charm.dashmaster': '[Dashmaster]',
+        'charm.sprintmaster': '[Sprintmaster]',
+        'charm.grubsong': '[Grubsong]',
+        'charm.grubberflys_elegy': "[Grubberfly's Elegy]",
+        'charm.fragile_heart': '[Fragile Heart] / [Unbreakable Heart]',
+        'charm.fragile_greed': '[Fragile Greed] / [Unbreakable Greed]',
+        'charm.fragile_strength': '[Fragile Strength] / [Unbreakable Strength]',
+        'charm.spell_twister': '[Spell Twister]',
+        'charm.steady_body': '[Steady Body]',
+        'charm.heavy_blow': '[Heavy Blow]',
+        'charm.quick_slash': '[Quick Slash]',
+        'charm.longnail': '[Longnail]',
+        'charm.mark_of_pride': '[Mark of Pride]',
+        'charm.fury_of_the_fallen': '[Fury of the Fallen]',
+        'charm.thorns_of_agony': '[Thorns of Agony]',
+        'charm.baldur_shell': '[Baldur Shell]',
+        'charm.flukenest': '[Flukenest]',
+        'charm.defenders_crest': "[Defender's Crest]",
+        'charm.glowing_womb': '[Glowing Womb]',
+        'charm.quick_focus': '[Quick Focus]',
+        'charm.deep_focus': '[Deep Focus]',
+        'charm.lifeblood_heart': '[Lifeblood Heart]',
+        'charm.lifeblood_core': '[Lifeblood Core]',
+        'charm.jonis_blessing': "[Joni's Blessing]",
+        'charm.hiveblood': '[Hiveblood]',
+        'charm.spore_shroom': '[Spore Shroom]',
+        'charm.sharp_shadow': '[Sharp Shadow]',
+        'charm.shape_of_unn': '[Shape of Unn]',
+        'charm.nailmasters_glory': "[Nailmaster's Glory]",
+        'charm.weaversong': '[Weaversong]',
+        'charm.dream_wielder': '[Dream Wielder]',
+        'charm.dreamshield': '[Dreamshield]',
+        'charm.grimmchild': '[Grimmchild] / [Carefree Melody]',
+        'charm.kingsoul': '[Kingsoul] / [Void Heart]',
+    },
+    colosseum: {
+        'colosseum.trial_of_the_warrior': '[Trial of the Warrior]',
+        'colosseum.trial_of_the_conqueror': '[Trial of the Conqueror]',
+        'colosseum.trial_of_the_fool': '[Trial of the Fool]',
+    },
+    dreamBosses: {
+        'dream_boss.failed_champion': '[Failed Champion]',
+        'dream_boss.grey_prince_zote': '[Grey Prince Zote]',
+        'dream_boss.lost_kin': '[Lost Kin]',
+        'dream_boss.white_defender': '[White Defender]',
+        'dream_boss.soul_tyrant': '[Soul Tyrant]',
+    },
+    dreamNail: {
+        'dream_nail.dream_nail': '[Dream Nail]',
+        'dream_nail.awoken_dream_nail': '[Awoken Dream Nail]',
+        'dream_nail.ascension': '[Ascension](Seer)',
+    },
+    dreamWarriors: {
+        'dream_warrior.elder_hu': '[Elder Hu]',
+        'dream_warrior.galien': '[Galien]',
+        'dream_warrior.gorb': '[Gorb]',
+        'dream_warrior.markoth': '[Markoth]',
+        'dream_warrior.marmu': '[Marmu]',
+        'dream_warrior.no_eyes': '[No Eyes]',
+        'dream_warrior.xero': '[Xero]',
+        'dream_warrior.nightmare_king_grimm':
+            '[Nightmare King Grimm] / [Banishment](Grimm Troupe (Quest))',
+    },
+    dreamers: {
+        'dreamer.herra_the_beast': '[Herra the Beast]',
+        'dreamer.lurien_the_watcher': '[Lurien the Watcher]',
+        'dreamer.monomon_the_teacher': '[Monomon the Teacher]',
+    },
+    equipment: {
+        'equipment.crystal_heart': '[Crystal Heart]',
+        'equipment.ismas_tear': "[Isma's Tear]",
+        'equipment.mantis_claw': '[Mantis Claw]',
+        'equipment.monarch_wings': '[Monarch Wings]',
+        'equipment.mothwing_cloak': '[Mothwing Cloak]',
+        'equipment.shade_cloak': '[Shade Cloak]',
+        'equipment.kings_brand': "[King's Brand]",
+    },
+    godhome: {
+        'godhome.godtuner': '[Godtuner]',
+        'godhome.pantheon_of_the_master': '[Pantheon of the Master]',
+        'godhome.pantheon_of_the_artist': '[Pantheon of the Artist]',
+        'godhome.pantheon_of_the_sage': '[Pantheon of the Sage]',
+        'godhome.pantheon_of_the_knight': '[Pantheon of the Knight]',
+        'godhome.pantheon_of_the_hallownest':
+            '[Pantheon of the Hallownest] (no percent)',
+    },
+    grubs: {
+        'grub.forgotten_crossroads_behind_husk_guard':
+            '[Forgotten Crossroads] behind [Husk Guard]',
+        'grub.forgotten_crossroads_fog_canyon_entrance':
+            '[Forgotten Crossroads] [Fog Canyon] entrance',
+        'grub.forgotten_crossroads_breakable_wall':
+            '[Forgotten Crossroads] breakable wall',
+        'grub.forgotten_crossroads_pogo':
+            '[Forgotten Crossroads] [Pogo](Nail#Nail-bouncing)',
+        'grub.forgotten_crossroads_on_a_ledge':
+            '[Forgotten Crossroads] on a ledge',
+        'grub.greenpath_with_a_moss_block_shortcut':
+            '[Greenpath] with a moss block shortcut',
+        'grub.greenpath_near_acid': '[Greenpath] near acid',
+        'grub.greenpath_behind_moss_knight': '[Greenpath] behind [Moss Knight]',
+        'grub.greenpath_in_the_middle_of_a_durandoo_room':
+            '[Greenpath] in the middle of a [Durandoo] room',
+        'grub.fungal_wastes_behind_a_line_of_funglings':
+            '[Fungal Wastes] behind a line of [Fungling]s',
+        'grub.fungal_wastes_near_spore_shroom':
+            '[Fungal Wastes] near [Spore Shroom]',
+        'grub.city_of_tears_on_a_ledge': '[City of Tears] on a ledge',
+        'grub.city_of_tears_behind_great_husk_sentry':
+            '[City of Tears] behind [Great Husk Sentry]',
+        'grub.city_of_tears_in_the_desolate_dive_dive':
+            '[City of Tears] in the [Desolate Dive] dive',
+        'grub.city_of_tears_under_the_entrance_to_the_tower_of_love':
+            '[City of Tears] under the entrance to the [Tower of Love]',
+        'grub.city_of_tears_room_leading_to_watcher_knight':
+            '[City of Tears] room leading to [Watcher Knight]',
+        'grub.crystal_peak_from_dirtmouth': '[Crystal Peak] from [Dirtmouth]',
+        'grub.crystal_peak_behind_presses': '[Crystal Peak] behind presses',
+        'grub.crystal_peak_near_crystal_heart':
+            '[Crystal Peak] near [Crystal Heart]',
+        'grub.crystal_peak_on_the_way_to_hallownests_crown':
+            "[Crystal Peak] on the way to [Hallownest's Crown]",
+        'grub.crystal_peak_vertical_conveyor_belts_lever':
+            '[Crystal Peak] vertical conveyor belts lever',
+        'grub.crystal_peak_from_the_top_room_with_presses':
+            '[Crystal Peak] from the top room with presses',
+        'grub.crystal_peak_in_the_crystallized_mound':
+            '[Crystal Peak] in the [Crystallized Mound]',
+        'grub.resting_grounds_crypts':
+            '[Resting Grounds] [Crypts](Resting Grounds#Crypts)',
+        'grub.royal_waterways_behind_a_wall_near_water':
+            '[Royal Waterways] behind a wall near water',
+        'grub.royal_waterways_from_the_kingdoms_edge':
+            "[Royal Waterways] from the [Kingdom's Edge]",
+        'grub.royal_waterways_above_ismas_tear':
+            "[Royal Waterways] above [Isma's Tear]",
+        'grub.howling_cliffs': '[Howling Cliffs]',
+        'grub.kingdoms_edge_under_oros_hut':
+            "[Kingdom's Edge] under [Oro]'s hut",
+        'grub.kingdoms_edge_behind_a_primal_aspid':
+            "[Kingdom's Edge] behind a [Primal Aspid]",
+        'grub.fog_canyon': '[Fog Canyon]',
+        'grub.queens_gardens_under_the_stag_station':
+            "[Queen's Gardens] under the [Stag] station",
+        'grub.queens_gardens_above_the_spiky_roof':
+            "[Queen's Gardens] above the spiky roof",
+        'grub.queens_gardens_near_white_lady':
+            "[Queen's Gardens] near [White Lady]",
+        'grub.deepnest_among_grub_mimics': '[Deepnest] among [Grub Mimic]s',
+        'grub.deepnest_above_the_spike_pit': '[Deepnest] above the spike pit',
+        'grub.deepnest_on_the_way_to_nosk': '[Deepnest] on the way to [Nosk]',
+        'grub.deepnest_near_the_weavers_den':
+            "[Deepnest] near the [Weavers' Den]",
+        'grub.deepnest_in_the_beasts_den': "[Deepnest] in the [Beast's Den]",
+        'grub.ancient_basin_above_broken_vessel':
+            '[Ancient Basin] above [Broken Vessel]',
+        'grub.ancient_basin_under_cloth': '[Ancient Basin] under [Cloth]',
+        'grub.the_hive_isolated_room': '[The Hive] isolated room',
+        'grub.the_hive': '[The Hive]',
+        'grub.tower_of_love_1': '[Tower of Love] #1',
+        'grub.tower_of_love_2': '[Tower of Love] #2',
+        'grub.tower_of_love_3': '[Tower of Love] #3',
+    },
+    items: {
+        'item.simple_key.sly': '[SIMPLE_KEY] [Simple Key] from [Sly]',
+        'item.simple_key.city_storerooms':
+            '[SIMPLE_KEY] [Simple Key] near [City Storerooms]',
+        'item.simple_key.ancient_basin':
+            '[SIMPLE_KEY] [Simple Key] in the [Ancient Basin]',
+        'item.simple_key.pale_lurker':
+            '[SIMPLE_KEY] [Simple Key] behind [Pale Lurker]',
+        'item.elegant_key': '[ELEGANT_KEY] [Elegant Key]',
+        'item.love_key': '[LOVE_KEY] [Love Key]',
+        'item.shopkeepers_key': "[SHOPKEEPER'S_KEY] [Shopkeeper's Key]",
+        'item.tram_pass': '[TRAM_PASS] [Tram Pass]',
+        'item.lumafly_lantern': '[Lumafly Lantern]',
+        'item.delicate_flower': '[Delicate Flower]',
+        'item.pale_ore.ancient_basin_below_cloth':
+            '[PALE_ORE] [Pale Ore] in [Ancient Basin] below [Cloth]',
+        'item.pale_ore.seer': '[PALE_ORE] [Pale Ore] awarded by the [Seer]',
+        'item.pale_ore.hallownests_crown':
+            "[PALE_ORE] [Pale Ore] on the [Hallownest's Crown]",
+        'item.pale_ore.nosks_lair': '[PALE_ORE] [Pale Ore] behind [Nosk]s lair',
+        'item.pale_ore.grubfather':
+            '[PALE_ORE] [Pale Ore] awarded by [Grubfather]',
+        'item.pale_ore.trial_of_the_conqueror':
+            '[PALE_ORE] [Pale Ore] reward in [Trial of the Conqueror]',
+        'item.charm_notch.salubra_1':
+            '[CHARM_NOTCH] [Charm Notch] from [Salubra] #1',
+        'item.charm_notch.salubra_2':
+            '[CHARM_NOTCH] [Charm Notch] from [Salubra] #2',
+        'item.charm_notch.salubra_3':
+            '[CHARM_NOTCH] [Charm Notch] from [Salubra] #3',
+        'item.charm_notch.salubra_4':
+            '[CHARM_NOTCH] [Charm Notch] from [Salubra] #4',
+        'item.charm_notch.fog_canyon':
+            '[CHARM_NOTCH] [Charm Notch] in [Fog Canyon]',
+        'item.charm_notch.fungal_wastes':
+            '[CHARM_NOTCH] [Charm Notch] in [Fungal Wastes]',
+        'item.charm_notch.colosseum_of_fools':
+            '[CHARM_NOTCH] [Charm Notch] from [Colosseum of Fools]',
+        'item.charm_notch.grimm': '[CHARM_NOTCH] [Charm Notch] from [Grimm]',
+    },
+    maskShards: {
+        'mask_shard.sly_1': '[Sly] #1',
+        'mask_shard.sly_2': '[Sly] #2',
+        'mask_shard.sly_3': '[Sly] #3',
+        'mask_shard.sly_4': '[Sly] #4',
+        'mask_shard.forgotten_crossroads_brooding_mawlek':
+            '[Forgotten Crossroads] [Brooding Mawlek]',
+        'mask_shard.grubfather': '[Grubfather]',
+        'mask_shard.forgotten_crossroads_goams':
+            '[Forgotten Crossroads] [Goams]',
+        'mask_shard.queens_station': "[Queen's Station]",
+        'mask_shard.brettas_house': "[Bretta]'s house",
+        'mask_shard.stone_sanctuary': '[Stone Sanctuary]',
+        'mask_shard.royal_waterways': '[Royal Waterways]',
+        'mask_shard.deepnest_from_fungal_core': '[Deepnest] from [Fungal Core]',
+        'mask_shard.enraged_guardian': '[Enraged Guardian]',
+        'mask_shard.the_hive': '[The Hive]',
+        'mask_shard.seer': '[Seer]',
+        'mask_shard.grey_mourner': '[Grey Mourner]',
+    },
+    nail: {
+        'nail.sharpened_nail': '[Sharpened Nail](Nail#Upgrades)',
+        'nail.channelled_nail': '[Channelled Nail](Nail#Upgrades)',
+        'nail.coiled_nail': '[Coiled Nail](Nail#Upgrades)',
+        'nail.pure_nail': '[Pure Nail](Nail#Upgrades)',
+    },
+    nailArts: {
+        'nail_art.cyclone_slash': '[Cyclone Slash]',
+        'nail_art.dash_slash': '[Dash Slash]',
+        'nail_art.great_slash': '[Great Slash]',
+    },
+    optionalBosses: {
+        'optional_boss.absolute_radiance': '[Absolute Radiance]',
+        'optional_boss.brothers_oro_and_mato': '[Brothers Oro & Mato]',
+        'optional_boss.crystal_guardian': '[Crystal Guardian]',
+        'optional_boss.enraged_guardian': '[Enraged Guardian]',
+        'optional_boss.flukemarm': '[Flukemarm]',
+        'optional_boss.god_tamer': '[God Tamer]',
+        'optional_boss.great_nailsage_sly': '[Great Nailsage Sly]',
+        'optional_boss.hollow_knight': '[Hollow Knight]',
+        'optional_boss.massive_moss_charger': '[Massive Moss Charger]',
+        'optional_boss.oblobbles': '[Oblobbles]',
+        'optional_boss.paintmaster_sheo': '[Paintmaster Sheo]',
+        'optional_boss.pure_vessel': '[Pure Vessel]',
+        'optional_boss.radiance': '[Radiance]',
+        'optional_boss.sisters_of_battle': '[Sisters of Battle]',
+        'optional_boss.soul_warrior': '[Soul Warrior]',
+        'optional_boss.vengefly_king': '[Vengefly King]',
+        'optional_boss.winged_nosk': '[Winged Nosk]',
+        'optional_boss.zote': '[Zote]',
+    },
+    relics: {
+        'relic.journal.greenpath_near_a_stag_station':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Greenpath] near a [Stag Station]",
+        'relic.journal.greenpath_near_fog_canyon_entrance':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Greenpath] near [Fog Canyon] entrance",
+        'relic.journal.fungal_wastes_near_shrumal_ogres':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Fungal Wastes] near [Shrumal Ogre]s",
+        'relic.journal.mantis_village':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] north of the [Mantis Village]",
+        'relic.journal.city_storerooms':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [City Storerooms]",
+        'relic.journal.kings_station':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] north of [King's Station]",
+        'relic.journal.pleasure_house':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Pleasure House]",
+        'relic.journal.howling_cliffs':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Howling Cliffs]",
+        'relic.journal.crystal_peak':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Crystal Peak]",
+        'relic.journal.crypts':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Crypts](Resting Grounds#Crypts)",
+        'relic.journal.royal_waterways':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Royal Waterways]",
+        'relic.journal.city_of_tears_entrance':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] near [City of Tears] entrance",
+        'relic.journal.cast_off_shell_bench':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] next to the [Cast-Off Shell] [Bench]",
+        'relic.journal.markoth':
+            "[WANDERER'S_JOURNAL] [Wanderer's Journal] near [Markoth]",
+        'relic.seal.grubfather':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] awarded by [Grubfather]',
+        'relic.seal.well_to_forgotten_crossroads':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] in the well to [Forgotten Crossroads]',
+        'relic.seal.thorns_of_agony':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] near [Thorns of Agony]',
+        'relic.seal.queens_station':
+            "[HALLOWNEST_SEAL] [Hallownest Seal] near [Queen's Station]",
+        'relic.seal.mantis_village':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] in [Mantis Village]',
+        'relic.seal.willoh':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] at the [Willoh]',
+        'relic.seal.overgrown_mound':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] near [Overgrown Mound]',
+        'relic.seal.forgotten_crossroads_in_fog_canyon_entrance':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] in [Forgotten Crossroads] in [Fog Canyon] entrance',
+        'relic.seal.crypts':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] in [Crypts](Resting Grounds#Crypts)',
+        'relic.seal.seer':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] awarded by the [Seer]',
+        'relic.seal.relic_seeker_lemm':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] near [Relic Seeker Lemm]',
+        'relic.seal.kings_station_stag_station':
+            "[HALLOWNEST_SEAL] [Hallownest Seal] above [King's Station] [Stag Station]",
+        'relic.seal.soul_master':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] near [Soul Master]',
+        'relic.seal.watcher_knight':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] behind [Watcher Knight]',
+        'relic.seal.beasts_den':
+            "[HALLOWNEST_SEAL] [Hallownest Seal] in [Beast's Den]",
+        'relic.seal.deepnest_near_mantis_lords':
+            '[HALLOWNEST_SEAL] [Hallownest Seal] in [Deepnest] near [Mantis Lords]',
+        'relic.seal.queens_gardens':
+            "[HALLOWNEST_SEAL] [Hallownest Seal] in [Queen's Gardens]",
+        'relic.idol.grubfather':
+            "[KING'S_IDOL] [King's Idol] awarded by [Grubfather]",
+        'relic.idol.crystal_peak':
+            "[KING'S_IDOL] [King's Idol] in [Crystal Peak]",
+        'relic.idol.spirits_glade':
+            "[KING'S_IDOL] [King's Idol] in [Spirits' Glade]",
+        'relic.idol.dung_defenders_secret_room':
+            "[KING'S_IDOL] [King's Idol] in [Dung Defender]'s secret room",
+        'relic.idol.howling_cliffs':
+            "[KING'S_IDOL] [King's Idol] in [Howling Cliffs]",
+        'relic.idol.colosseum_of_fools':
+            "[KING'S_IDOL] [King's Idol] under [Colosseum of Fools]",
+        'relic.idol.pale_lurker':
+            "[KING'S_IDOL] [King's Idol] near [Pale Lurker]",
+        'relic.idol.deepnest_near_zote':
+            "[KING'S_IDOL] [King's Idol] in [Deepnest] near [Zote]",
+        'relic.egg.lifeblood_core':
+            '[ARCANE_EGG] [Arcane Egg] below [Lifeblood Core]',
+        'relic.egg.shade_cloak': '[ARCANE_EGG] [Arcane Egg] near [Shade Cloak]',
+        'relic.egg.birthplace': '[ARCANE_EGG] [Arcane Egg] in [Birthplace]',
+        'relic.egg.seer': '[ARCANE_EGG] [Arcane Egg] awarded by the [Seer]',
+    },
+    spells: {
+        'spell.desolate_dive': '[Desolate Dive]',
+        'spell.descending_dark': '[Descending Dark]',
+        'spell.howling_wraiths': '[Howling Wraiths]',
+        'spell.abyss_shriek': '[Abyss Shriek]',
+        'spell.vengeful_spirit': '[Vengeful Spirit]',
+        'spell.shade_soul': '[Shade Soul]',
+    },
+    vesselFragments: {
+        'vessel_fragment.sly_1': '[Sly] #1',
+        'vessel_fragment.sly_2': '[Sly] #2',
+        'vessel_fragment.greenpath': '[Greenpath]',
+        'vessel_fragment.left_of_the_lift_in_forgotten_crossroads':
+            'Left of the lift in [Forgotten Crossroads]',
+        'vessel_fragment.above_kings_station_near_a_lift':
+            "Above [King's Station] near a lift",
+        'vessel_fragment.deepnest': '[Deepnest]',
+        'vessel_fragment.stag_nest': '[Stag Nest]',
+        'vessel_fragment.seer': '[Seer]',
+        'vessel_fragment.ancient_basin_fountain': '[Ancient Basin] fountain',
+    },
+    whisperingRoots: {
+        'whispering_root.ancestral_mound': '[Ancestral Mound]',
+        'whispering_root.city_of_tears': '[City of Tears]',
+        'whispering_root.crystal_peak': '[Crystal Peak]',
+        'whispering_root.deepnest': '[Deepnest]',
+        'whispering_root.forgotten_crossroads': '[Forgotten Crossroads]',
+        'whispering_root.fungal_wastes_near_fog_canyon':
+            '[Fungal Wastes] (near [Fog Canyon])',
+        'whispering_root.fungal_wastes_above_mantis_village':
+            '[Fungal Wastes] (above [Mantis Village])',
+        'whispering_root.greenpath': '[Greenpath]',
+        'whispering_root.the_hive': '[The Hive]',
+        'whispering_root.howling_cliffs': '[Howling Cliffs]',
+        'whispering_root.kingdoms_edge': "[Kingdom's Edge]",
+        'whispering_root.queens_gardens': "[Queen's Gardens]",
+        'whispering_root.resting_grounds': '[Resting Grounds]',
+        'whispering_root.royal_waterways': '[Royal Waterways]',
+        'whispering_root.spirits_glade': "[Spirits' Glade]",
+    },
+};
+
+// TODO: the planner only has Lace so far
+const SILKSONG_CHECK_IDS: PartialDeep<CheckIds<'silksong'>> = {
+    bosses: {
+        'boss.lace_1': '[Lace]',
+    },
+};
+
+const CHECK_IDS = {
+    'hollow-knight': HOLLOW_KNIGHT_CHECK_IDS,
+    silksong: SILKSONG_CHECK_IDS,
+} as const;
+
+export default CHECK_IDS;
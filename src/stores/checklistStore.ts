@@ -19,11 +19,12 @@ import {
     HollowKnightChecklistState,
     RequirementCheckErrors,
     SaveFile,
-    SaveFileData,
     SectionNames,
 } from '../types/checklist';
+import type { CheckState } from '../types/gameSer';
 import partialDeepEqual, { Comparable } from '../util/partialDeepEqual';
 import { typedEntries, typedKeys, typedValues } from '../util/typedObject';
+import CHECK_IDS from './CHECK_IDS';
 import INITIAL_CHECKLIST_STATE from './INITIAL_CHECKLIST_STATE';
 import useUiStore from './uiStore';
 
@@ -285,20 +286,54 @@ const createChecklistStore = <Game extends GameKey>(
                     setFromSaveFile: (savefile: SaveFile) => {
                         const game = typedKeys(savefile)[0]!;
                         type Save = typeof game;
-                        const save = savefile[game]! as SaveFileData<Save>;
+                        // Also has derived categories, like the journal,
+                        // which aren't planner sections.
+                        const save: Record<
+                            string,
+                            Record<string, CheckState>
+                        > = savefile[game]!;
+
+                        const ids = CHECK_IDS[game] as Partial<
+                            Record<
+                                string,
+                                Partial<
+                                    Record<
+                                        string,
+                                        CheckNames<Save, SectionNames<Save>>
+                                    >
+                                >
+                            >
+                        >;
 
                         useChecklistStore(game).setState(state => {
-                            typedEntries(save).forEach(
-                                ([sectionName, section]) => {
-                                    Array.from(section.entries()).forEach(
-                                        ([checkName, checked]) => {
-                                            const section = state.checks[
-                                                sectionName
-                                            ] as ChecksSection<
-                                                Save,
-                                                SectionNames<Save>
-                                            >;
-                                            const check = section[checkName];
+                            Object.entries(save).forEach(
+                                ([name, section]) => {
+                                    const sectionName =
+                                        name as SectionNames<Save>;
+                                    const planned = state.checks[
+                                        sectionName
+                                    ] as
+                                        | ChecksSection<
+                                              Save,
+                                              SectionNames<Save>
+                                          >
+                                        | undefined;
+                                    // The parser keys checks by id, see
+                                    // which of ours each of them is.
+                                    const names = ids[sectionName];
+
+                                    Object.entries(section).forEach(
+                                        ([id, { checked }]) => {
+                                            const checkName = names?.[id];
+                                            const check =
+                                                checkName &&
+                                                planned?.[checkName];
+
+                                            // The parser may report checks
+                                            // the planner doesn't list yet.
+                                            if (!check) {
+                                                return;
+                                            }
 
                                             handleCheck<Save>(
                                                 state,
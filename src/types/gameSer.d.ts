@@ -0,0 +1,987 @@
+// Generated by `hksave schema --ts`, do not edit.
+
+/** State of a single check in a parsed save. */
+export type CheckState = {
+    /** Plain text label, without the wiki markup. */
+    label: string;
+    /** Wiki pages the label links to. */
+    wiki: string[];
+    checked: boolean;
+};
+
+/** Hollow Knight check labels, by planner category. */
+export type HollowKnightChecksKeys = {
+    bosses:
+        | '[Broken Vessel]'
+        | '[Brooding Mawlek]'
+        | '[The Collector]'
+        | '[Dung Defender]'
+        | '[False Knight]'
+        | '[Grimm]'
+        | '[Gruz Mother]'
+        | '[Hive Knight]'
+        | '[Hornet Protector]'
+        | '[Hornet Sentinel]'
+        | '[Mantis Lords]'
+        | '[Nosk]'
+        | '[Soul Master]'
+        | '[Traitor Lord]'
+        | '[Uumuu]'
+        | '[Watcher Knight]';
+    optionalBosses:
+        | '[Absolute Radiance]'
+        | '[Brothers Oro & Mato]'
+        | '[Crystal Guardian]'
+        | '[Enraged Guardian]'
+        | '[Flukemarm]'
+        | '[God Tamer]'
+        | '[Great Nailsage Sly]'
+        | '[Hollow Knight]'
+        | '[Massive Moss Charger]'
+        | '[Oblobbles]'
+        | '[Paintmaster Sheo]'
+        | '[Pure Vessel]'
+        | '[Radiance]'
+        | '[Sisters of Battle]'
+        | '[Soul Warrior]'
+        | '[Vengefly King]'
+        | '[Winged Nosk]'
+        | '[Zote]';
+    equipment:
+        | '[Crystal Heart]'
+        | "[Isma's Tear]"
+        | '[Mantis Claw]'
+        | '[Monarch Wings]'
+        | '[Mothwing Cloak]'
+        | '[Shade Cloak]'
+        | "[King's Brand]";
+    nail:
+        | '[Sharpened Nail](Nail#Upgrades)'
+        | '[Channelled Nail](Nail#Upgrades)'
+        | '[Coiled Nail](Nail#Upgrades)'
+        | '[Pure Nail](Nail#Upgrades)';
+    dreamNail: '[Dream Nail]' | '[Awoken Dream Nail]' | '[Ascension](Seer)';
+    nailArts: '[Cyclone Slash]' | '[Dash Slash]' | '[Great Slash]';
+    spells:
+        | '[Desolate Dive]'
+        | '[Descending Dark]'
+        | '[Howling Wraiths]'
+        | '[Abyss Shriek]'
+        | '[Vengeful Spirit]'
+        | '[Shade Soul]';
+    charms:
+        | '[Wayward Compass]'
+        | '[Gathering Swarm]'
+        | '[Stalwart Shell]'
+        | '[Soul Catcher]'
+        | '[Shaman Stone]'
+        | '[Soul Eater]'
+        | '[Dashmaster]'
+        | '[Sprintmaster]'
+        | '[Grubsong]'
+        | "[Grubberfly's Elegy]"
+        | '[Fragile Heart] / [Unbreakable Heart]'
+        | '[Fragile Greed] / [Unbreakable Greed]'
+        | '[Fragile Strength] / [Unbreakable Strength]'
+        | '[Spell Twister]'
+        | '[Steady Body]'
+        | '[Heavy Blow]'
+        | '[Quick Slash]'
+        | '[Longnail]'
+        | '[Mark of Pride]'
+        | '[Fury of the Fallen]'
+        | '[Thorns of Agony]'
+        | '[Baldur Shell]'
+        | '[Flukenest]'
+        | "[Defender's Crest]"
+        | '[Glowing Womb]'
+        | '[Quick Focus]'
+        | '[Deep Focus]'
+        | '[Lifeblood Heart]'
+        | '[Lifeblood Core]'
+        | "[Joni's Blessing]"
+        | '[Hiveblood]'
+        | '[Spore Shroom]'
+        | '[Sharp Shadow]'
+        | '[Shape of Unn]'
+        | "[Nailmaster's Glory]"
+        | '[Weaversong]'
+        | '[Dream Wielder]'
+        | '[Dreamshield]'
+        | '[Grimmchild] / [Carefree Melody]'
+        | '[Kingsoul] / [Void Heart]';
+    maskShards:
+        | '[Sly] #1'
+        | '[Sly] #2'
+        | '[Sly] #3'
+        | '[Sly] #4'
+        | '[Forgotten Crossroads] [Brooding Mawlek]'
+        | '[Grubfather]'
+        | '[Forgotten Crossroads] [Goams]'
+        | "[Queen's Station]"
+        | "[Bretta]'s house"
+        | '[Stone Sanctuary]'
+        | '[Royal Waterways]'
+        | '[Deepnest] from [Fungal Core]'
+        | '[Enraged Guardian]'
+        | '[The Hive]'
+        | '[Seer]'
+        | '[Grey Mourner]';
+    vesselFragments:
+        | '[Sly] #1'
+        | '[Sly] #2'
+        | '[Greenpath]'
+        | 'Left of the lift in [Forgotten Crossroads]'
+        | "Above [King's Station] near a lift"
+        | '[Deepnest]'
+        | '[Stag Nest]'
+        | '[Seer]'
+        | '[Ancient Basin] fountain';
+    dreamers:
+        | '[Herra the Beast]'
+        | '[Lurien the Watcher]'
+        | '[Monomon the Teacher]';
+    dreamWarriors:
+        | '[Elder Hu]'
+        | '[Galien]'
+        | '[Gorb]'
+        | '[Markoth]'
+        | '[Marmu]'
+        | '[No Eyes]'
+        | '[Xero]'
+        | '[Nightmare King Grimm] / [Banishment](Grimm Troupe (Quest))';
+    dreamBosses:
+        | '[Failed Champion]'
+        | '[Grey Prince Zote]'
+        | '[Lost Kin]'
+        | '[White Defender]'
+        | '[Soul Tyrant]';
+    colosseum:
+        | '[Trial of the Warrior]'
+        | '[Trial of the Conqueror]'
+        | '[Trial of the Fool]';
+    godhome:
+        | '[Godtuner]'
+        | '[Pantheon of the Master]'
+        | '[Pantheon of the Artist]'
+        | '[Pantheon of the Sage]'
+        | '[Pantheon of the Knight]'
+        | '[Pantheon of the Hallownest] (no percent)';
+    grubs:
+        | '[Forgotten Crossroads] behind [Husk Guard]'
+        | '[Forgotten Crossroads] [Fog Canyon] entrance'
+        | '[Forgotten Crossroads] breakable wall'
+        | '[Forgotten Crossroads] [Pogo](Nail#Nail-bouncing)'
+        | '[Forgotten Crossroads] on a ledge'
+        | '[Greenpath] with a moss block shortcut'
+        | '[Greenpath] near acid'
+        | '[Greenpath] behind [Moss Knight]'
+        | '[Greenpath] in the middle of a [Durandoo] room'
+        | '[Fungal Wastes] behind a line of [Fungling]s'
+        | '[Fungal Wastes] near [Spore Shroom]'
+        | '[City of Tears] on a ledge'
+        | '[City of Tears] behind [Great Husk Sentry]'
+        | '[City of Tears] in the [Desolate Dive] dive'
+        | '[City of Tears] under the entrance to the [Tower of Love]'
+        | '[City of Tears] room leading to [Watcher Knight]'
+        | '[Crystal Peak] from [Dirtmouth]'
+        | '[Crystal Peak] behind presses'
+        | '[Crystal Peak] near [Crystal Heart]'
+        | "[Crystal Peak] on the way to [Hallownest's Crown]"
+        | '[Crystal Peak] vertical conveyor belts lever'
+        | '[Crystal Peak] from the top room with presses'
+        | '[Crystal Peak] in the [Crystallized Mound]'
+        | '[Resting Grounds] [Crypts](Resting Grounds#Crypts)'
+        | '[Royal Waterways] behind a wall near water'
+        | "[Royal Waterways] from the [Kingdom's Edge]"
+        | "[Royal Waterways] above [Isma's Tear]"
+        | '[Howling Cliffs]'
+        | "[Kingdom's Edge] under [Oro]'s hut"
+        | "[Kingdom's Edge] behind a [Primal Aspid]"
+        | '[Fog Canyon]'
+        | "[Queen's Gardens] under the [Stag] station"
+        | "[Queen's Gardens] above the spiky roof"
+        | "[Queen's Gardens] near [White Lady]"
+        | '[Deepnest] among [Grub Mimic]s'
+        | '[Deepnest] above the spike pit'
+        | '[Deepnest] on the way to [Nosk]'
+        | "[Deepnest] near the [Weavers' Den]"
+        | "[Deepnest] in the [Beast's Den]"
+        | '[Ancient Basin] above [Broken Vessel]'
+        | '[Ancient Basin] under [Cloth]'
+        | '[The Hive] isolated room'
+        | '[The Hive]'
+        | '[Tower of Love] #1'
+        | '[Tower of Love] #2'
+        | '[Tower of Love] #3';
+    items:
+        | '[SIMPLE_KEY] [Simple Key] from [Sly]'
+        | '[SIMPLE_KEY] [Simple Key] near [City Storerooms]'
+        | '[SIMPLE_KEY] [Simple Key] in the [Ancient Basin]'
+        | '[SIMPLE_KEY] [Simple Key] behind [Pale Lurker]'
+        | '[ELEGANT_KEY] [Elegant Key]'
+        | '[LOVE_KEY] [Love Key]'
+        | "[SHOPKEEPER'S_KEY] [Shopkeeper's Key]"
+        | '[TRAM_PASS] [Tram Pass]'
+        | '[Lumafly Lantern]'
+        | '[Delicate Flower]'
+        | '[PALE_ORE] [Pale Ore] in [Ancient Basin] below [Cloth]'
+        | '[PALE_ORE] [Pale Ore] awarded by the [Seer]'
+        | "[PALE_ORE] [Pale Ore] on the [Hallownest's Crown]"
+        | '[PALE_ORE] [Pale Ore] behind [Nosk]s lair'
+        | '[PALE_ORE] [Pale Ore] awarded by [Grubfather]'
+        | '[PALE_ORE] [Pale Ore] reward in [Trial of the Conqueror]'
+        | '[CHARM_NOTCH] [Charm Notch] from [Salubra] #1'
+        | '[CHARM_NOTCH] [Charm Notch] from [Salubra] #2'
+        | '[CHARM_NOTCH] [Charm Notch] from [Salubra] #3'
+        | '[CHARM_NOTCH] [Charm Notch] from [Salubra] #4'
+        | '[CHARM_NOTCH] [Charm Notch] in [Fog Canyon]'
+        | '[CHARM_NOTCH] [Charm Notch] in [Fungal Wastes]'
+        | '[CHARM_NOTCH] [Charm Notch] from [Colosseum of Fools]'
+        | '[CHARM_NOTCH] [Charm Notch] from [Grimm]';
+    relics:
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Greenpath] near a [Stag Station]"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Greenpath] near [Fog Canyon] entrance"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Fungal Wastes] near [Shrumal Ogre]s"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] north of the [Mantis Village]"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [City Storerooms]"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] north of [King's Station]"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Pleasure House]"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Howling Cliffs]"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Crystal Peak]"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Crypts](Resting Grounds#Crypts)"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] in [Royal Waterways]"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] near [City of Tears] entrance"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] next to the [Cast-Off Shell] [Bench]"
+        | "[WANDERER'S_JOURNAL] [Wanderer's Journal] near [Markoth]"
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] awarded by [Grubfather]'
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] in the well to [Forgotten Crossroads]'
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] near [Thorns of Agony]'
+        | "[HALLOWNEST_SEAL] [Hallownest Seal] near [Queen's Station]"
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] in [Mantis Village]'
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] at the [Willoh]'
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] near [Overgrown Mound]'
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] in [Forgotten Crossroads] in [Fog Canyon] entrance'
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] in [Crypts](Resting Grounds#Crypts)'
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] awarded by the [Seer]'
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] near [Relic Seeker Lemm]'
+        | "[HALLOWNEST_SEAL] [Hallownest Seal] above [King's Station] [Stag Station]"
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] near [Soul Master]'
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] behind [Watcher Knight]'
+        | "[HALLOWNEST_SEAL] [Hallownest Seal] in [Beast's Den]"
+        | '[HALLOWNEST_SEAL] [Hallownest Seal] in [Deepnest] near [Mantis Lords]'
+        | "[HALLOWNEST_SEAL] [Hallownest Seal] in [Queen's Gardens]"
+        | "[KING'S_IDOL] [King's Idol] awarded by [Grubfather]"
+        | "[KING'S_IDOL] [King's Idol] in [Crystal Peak]"
+        | "[KING'S_IDOL] [King's Idol] in [Spirits' Glade]"
+        | "[KING'S_IDOL] [King's Idol] in [Dung Defender]'s secret room"
+        | "[KING'S_IDOL] [King's Idol] in [Howling Cliffs]"
+        | "[KING'S_IDOL] [King's Idol] under [Colosseum of Fools]"
+        | "[KING'S_IDOL] [King's Idol] near [Pale Lurker]"
+        | "[KING'S_IDOL] [King's Idol] in [Deepnest] near [Zote]"
+        | '[ARCANE_EGG] [Arcane Egg] below [Lifeblood Core]'
+        | '[ARCANE_EGG] [Arcane Egg] near [Shade Cloak]'
+        | '[ARCANE_EGG] [Arcane Egg] in [Birthplace]'
+        | '[ARCANE_EGG] [Arcane Egg] awarded by the [Seer]';
+    whisperingRoots:
+        | '[Ancestral Mound]'
+        | '[City of Tears]'
+        | '[Crystal Peak]'
+        | '[Deepnest]'
+        | '[Forgotten Crossroads]'
+        | '[Fungal Wastes] (near [Fog Canyon])'
+        | '[Fungal Wastes] (above [Mantis Village])'
+        | '[Greenpath]'
+        | '[The Hive]'
+        | '[Howling Cliffs]'
+        | "[Kingdom's Edge]"
+        | "[Queen's Gardens]"
+        | '[Resting Grounds]'
+        | '[Royal Waterways]'
+        | "[Spirits' Glade]";
+};
+
+/** Hollow Knight check ids, by category. */
+export type HollowKnightCheckIds = {
+    bosses:
+        | 'boss.broken_vessel'
+        | 'boss.brooding_mawlek'
+        | 'boss.the_collector'
+        | 'boss.dung_defender'
+        | 'boss.false_knight'
+        | 'boss.grimm'
+        | 'boss.gruz_mother'
+        | 'boss.hive_knight'
+        | 'boss.hornet_protector'
+        | 'boss.hornet_sentinel'
+        | 'boss.mantis_lords'
+        | 'boss.nosk'
+        | 'boss.soul_master'
+        | 'boss.traitor_lord'
+        | 'boss.uumuu'
+        | 'boss.watcher_knight';
+    optionalBosses:
+        | 'optional_boss.absolute_radiance'
+        | 'optional_boss.brothers_oro_and_mato'
+        | 'optional_boss.crystal_guardian'
+        | 'optional_boss.enraged_guardian'
+        | 'optional_boss.flukemarm'
+        | 'optional_boss.god_tamer'
+        | 'optional_boss.great_nailsage_sly'
+        | 'optional_boss.hollow_knight'
+        | 'optional_boss.massive_moss_charger'
+        | 'optional_boss.oblobbles'
+        | 'optional_boss.paintmaster_sheo'
+        | 'optional_boss.pure_vessel'
+        | 'optional_boss.radiance'
+        | 'optional_boss.sisters_of_battle'
+        | 'optional_boss.soul_warrior'
+        | 'optional_boss.vengefly_king'
+        | 'optional_boss.winged_nosk'
+        | 'optional_boss.zote';
+    equipment:
+        | 'equipment.crystal_heart'
+        | 'equipment.ismas_tear'
+        | 'equipment.mantis_claw'
+        | 'equipment.monarch_wings'
+        | 'equipment.mothwing_cloak'
+        | 'equipment.shade_cloak'
+        | 'equipment.kings_brand';
+    nail:
+        | 'nail.sharpened_nail'
+        | 'nail.channelled_nail'
+        | 'nail.coiled_nail'
+        | 'nail.pure_nail';
+    dreamNail:
+        | 'dream_nail.dream_nail'
+        | 'dream_nail.awoken_dream_nail'
+        | 'dream_nail.ascension';
+    nailArts:
+        | 'nail_art.cyclone_slash'
+        | 'nail_art.dash_slash'
+        | 'nail_art.great_slash';
+    spells:
+        | 'spell.desolate_dive'
+        | 'spell.descending_dark'
+        | 'spell.howling_wraiths'
+        | 'spell.abyss_shriek'
+        | 'spell.vengeful_spirit'
+        | 'spell.shade_soul';
+    charms:
+        | 'charm.wayward_compass'
+        | 'charm.gathering_swarm'
+        | 'charm.stalwart_shell'
+        | 'charm.soul_catcher'
+        | 'charm.shaman_stone'
+        | 'charm.soul_eater'
+        | 'charm.dashmaster'
+        | 'charm.sprintmaster'
+        | 'charm.grubsong'
+        | 'charm.grubberflys_elegy'
+        | 'charm.fragile_heart'
+        | 'charm.fragile_greed'
+        | 'charm.fragile_strength'
+        | 'charm.spell_twister'
+        | 'charm.steady_body'
+        | 'charm.heavy_blow'
+        | 'charm.quick_slash'
+        | 'charm.longnail'
+        | 'charm.mark_of_pride'
+        | 'charm.fury_of_the_fallen'
+        | 'charm.thorns_of_agony'
+        | 'charm.baldur_shell'
+        | 'charm.flukenest'
+        | 'charm.defenders_crest'
+        | 'charm.glowing_womb'
+        | 'charm.quick_focus'
+        | 'charm.deep_focus'
+        | 'charm.lifeblood_heart'
+        | 'charm.lifeblood_core'
+        | 'charm.jonis_blessing'
+        | 'charm.hiveblood'
+        | 'charm.spore_shroom'
+        | 'charm.sharp_shadow'
+        | 'charm.shape_of_unn'
+        | 'charm.nailmasters_glory'
+        | 'charm.weaversong'
+        | 'charm.dream_wielder'
+        | 'charm.dreamshield'
+        | 'charm.grimmchild'
+        | 'charm.kingsoul';
+    maskShards:
+        | 'mask_shard.sly_1'
+        | 'mask_shard.sly_2'
+        | 'mask_shard.sly_3'
+        | 'mask_shard.sly_4'
+        | 'mask_shard.forgotten_crossroads_brooding_mawlek'
+        | 'mask_shard.grubfather'
+        | 'mask_shard.forgotten_crossroads_goams'
+        | 'mask_shard.queens_station'
+        | 'mask_shard.brettas_house'
+        | 'mask_shard.stone_sanctuary'
+        | 'mask_shard.royal_waterways'
+        | 'mask_shard.deepnest_from_fungal_core'
+        | 'mask_shard.enraged_guardian'
+        | 'mask_shard.the_hive'
+        | 'mask_shard.seer'
+        | 'mask_shard.grey_mourner';
+    vesselFragments:
+        | 'vessel_fragment.sly_1'
+        | 'vessel_fragment.sly_2'
+        | 'vessel_fragment.greenpath'
+        | 'vessel_fragment.left_of_the_lift_in_forgotten_crossroads'
+        | 'vessel_fragment.above_kings_station_near_a_lift'
+        | 'vessel_fragment.deepnest'
+        | 'vessel_fragment.stag_nest'
+        | 'vessel_fragment.seer'
+        | 'vessel_fragment.ancient_basin_fountain';
+    dreamers:
+        | 'dreamer.herra_the_beast'
+        | 'dreamer.lurien_the_watcher'
+        | 'dreamer.monomon_the_teacher';
+    dreamWarriors:
+        | 'dream_warrior.elder_hu'
+        | 'dream_warrior.galien'
+        | 'dream_warrior.gorb'
+        | 'dream_warrior.markoth'
+        | 'dream_warrior.marmu'
+        | 'dream_warrior.no_eyes'
+        | 'dream_warrior.xero'
+        | 'dream_warrior.nightmare_king_grimm';
+    dreamBosses:
+        | 'dream_boss.failed_champion'
+        | 'dream_boss.grey_prince_zote'
+        | 'dream_boss.lost_kin'
+        | 'dream_boss.white_defender'
+        | 'dream_boss.soul_tyrant';
+    colosseum:
+        | 'colosseum.trial_of_the_warrior'
+        | 'colosseum.trial_of_the_conqueror'
+        | 'colosseum.trial_of_the_fool';
+    godhome:
+        | 'godhome.godtuner'
+        | 'godhome.pantheon_of_the_master'
+        | 'godhome.pantheon_of_the_artist'
+        | 'godhome.pantheon_of_the_sage'
+        | 'godhome.pantheon_of_the_knight'
+        | 'godhome.pantheon_of_the_hallownest';
+    grubs:
+        | 'grub.forgotten_crossroads_behind_husk_guard'
+        | 'grub.forgotten_crossroads_fog_canyon_entrance'
+        | 'grub.forgotten_crossroads_breakable_wall'
+        | 'grub.forgotten_crossroads_pogo'
+        | 'grub.forgotten_crossroads_on_a_ledge'
+        | 'grub.greenpath_with_a_moss_block_shortcut'
+        | 'grub.greenpath_near_acid'
+        | 'grub.greenpath_behind_moss_knight'
+        | 'grub.greenpath_in_the_middle_of_a_durandoo_room'
+        | 'grub.fungal_wastes_behind_a_line_of_funglings'
+        | 'grub.fungal_wastes_near_spore_shroom'
+        | 'grub.city_of_tears_on_a_ledge'
+        | 'grub.city_of_tears_behind_great_husk_sentry'
+        | 'grub.city_of_tears_in_the_desolate_dive_dive'
+        | 'grub.city_of_tears_under_the_entrance_to_the_tower_of_love'
+        | 'grub.city_of_tears_room_leading_to_watcher_knight'
+        | 'grub.crystal_peak_from_dirtmouth'
+        | 'grub.crystal_peak_behind_presses'
+        | 'grub.crystal_peak_near_crystal_heart'
+        | 'grub.crystal_peak_on_the_way_to_hallownests_crown'
+        | 'grub.crystal_peak_vertical_conveyor_belts_lever'
+        | 'grub.crystal_peak_from_the_top_room_with_presses'
+        | 'grub.crystal_peak_in_the_crystallized_mound'
+        | 'grub.resting_grounds_crypts'
+        | 'grub.royal_waterways_behind_a_wall_near_water'
+        | 'grub.royal_waterways_from_the_kingdoms_edge'
+        | 'grub.royal_waterways_above_ismas_tear'
+        | 'grub.howling_cliffs'
+        | 'grub.kingdoms_edge_under_oros_hut'
+        | 'grub.kingdoms_edge_behind_a_primal_aspid'
+        | 'grub.fog_canyon'
+        | 'grub.queens_gardens_under_the_stag_station'
+        | 'grub.queens_gardens_above_the_spiky_roof'
+        | 'grub.queens_gardens_near_white_lady'
+        | 'grub.deepnest_among_grub_mimics'
+        | 'grub.deepnest_above_the_spike_pit'
+        | 'grub.deepnest_on_the_way_to_nosk'
+        | 'grub.deepnest_near_the_weavers_den'
+        | 'grub.deepnest_in_the_beasts_den'
+        | 'grub.ancient_basin_above_broken_vessel'
+        | 'grub.ancient_basin_under_cloth'
+        | 'grub.the_hive_isolated_room'
+        | 'grub.the_hive'
+        | 'grub.tower_of_love_1'
+        | 'grub.tower_of_love_2'
+        | 'grub.tower_of_love_3';
+    items:
+        | 'item.simple_key.sly'
+        | 'item.simple_key.city_storerooms'
+        | 'item.simple_key.ancient_basin'
+        | 'item.simple_key.pale_lurker'
+        | 'item.elegant_key'
+        | 'item.love_key'
+        | 'item.shopkeepers_key'
+        | 'item.tram_pass'
+        | 'item.lumafly_lantern'
+        | 'item.delicate_flower'
+        | 'item.pale_ore.ancient_basin_below_cloth'
+        | 'item.pale_ore.seer'
+        | 'item.pale_ore.hallownests_crown'
+        | 'item.pale_ore.nosks_lair'
+        | 'item.pale_ore.grubfather'
+        | 'item.pale_ore.trial_of_the_conqueror'
+        | 'item.charm_notch.salubra_1'
+        | 'item.charm_notch.salubra_2'
+        | 'item.charm_notch.salubra_3'
+        | 'item.charm_notch.salubra_4'
+        | 'item.charm_notch.fog_canyon'
+        | 'item.charm_notch.fungal_wastes'
+        | 'item.charm_notch.colosseum_of_fools'
+        | 'item.charm_notch.grimm';
+    relics:
+        | 'relic.journal.greenpath_near_a_stag_station'
+        | 'relic.journal.greenpath_near_fog_canyon_entrance'
+        | 'relic.journal.fungal_wastes_near_shrumal_ogres'
+        | 'relic.journal.mantis_village'
+        | 'relic.journal.city_storerooms'
+        | 'relic.journal.kings_station'
+        | 'relic.journal.pleasure_house'
+        | 'relic.journal.howling_cliffs'
+        | 'relic.journal.crystal_peak'
+        | 'relic.journal.crypts'
+        | 'relic.journal.royal_waterways'
+        | 'relic.journal.city_of_tears_entrance'
+        | 'relic.journal.cast_off_shell_bench'
+        | 'relic.journal.markoth'
+        | 'relic.seal.grubfather'
+        | 'relic.seal.well_to_forgotten_crossroads'
+        | 'relic.seal.thorns_of_agony'
+        | 'relic.seal.queens_station'
+        | 'relic.seal.mantis_village'
+        | 'relic.seal.willoh'
+        | 'relic.seal.overgrown_mound'
+        | 'relic.seal.forgotten_crossroads_in_fog_canyon_entrance'
+        | 'relic.seal.crypts'
+        | 'relic.seal.seer'
+        | 'relic.seal.relic_seeker_lemm'
+        | 'relic.seal.kings_station_stag_station'
+        | 'relic.seal.soul_master'
+        | 'relic.seal.watcher_knight'
+        | 'relic.seal.beasts_den'
+        | 'relic.seal.deepnest_near_mantis_lords'
+        | 'relic.seal.queens_gardens'
+        | 'relic.idol.grubfather'
+        | 'relic.idol.crystal_peak'
+        | 'relic.idol.spirits_glade'
+        | 'relic.idol.dung_defenders_secret_room'
+        | 'relic.idol.howling_cliffs'
+        | 'relic.idol.colosseum_of_fools'
+        | 'relic.idol.pale_lurker'
+        | 'relic.idol.deepnest_near_zote'
+        | 'relic.egg.lifeblood_core'
+        | 'relic.egg.shade_cloak'
+        | 'relic.egg.birthplace'
+        | 'relic.egg.seer';
+    whisperingRoots:
+        | 'whispering_root.ancestral_mound'
+        | 'whispering_root.city_of_tears'
+        | 'whispering_root.crystal_peak'
+        | 'whispering_root.deepnest'
+        | 'whispering_root.forgotten_crossroads'
+        | 'whispering_root.fungal_wastes_near_fog_canyon'
+        | 'whispering_root.fungal_wastes_above_mantis_village'
+        | 'whispering_root.greenpath'
+        | 'whispering_root.the_hive'
+        | 'whispering_root.howling_cliffs'
+        | 'whispering_root.kingdoms_edge'
+        | 'whispering_root.queens_gardens'
+        | 'whispering_root.resting_grounds'
+        | 'whispering_root.royal_waterways'
+        | 'whispering_root.spirits_glade';
+    geoRocks: string;
+    huntersJournal: string;
+    achievements:
+        | 'achievement.charmed'
+        | 'achievement.enchanted'
+        | 'achievement.blessed'
+        | 'achievement.protected'
+        | 'achievement.masked'
+        | 'achievement.soulful'
+        | 'achievement.worldsoul'
+        | 'achievement.grubfriend'
+        | 'achievement.metamorphosis'
+        | 'achievement.keen_hunter'
+        | 'achievement.true_hunter'
+        | 'achievement.attunement'
+        | 'achievement.awakening'
+        | 'achievement.ascension'
+        | 'achievement.falsehood'
+        | 'achievement.test_of_resolve'
+        | 'achievement.proof_of_resolve'
+        | 'achievement.teacher'
+        | 'achievement.watcher'
+        | 'achievement.beast'
+        | 'achievement.grimm'
+        | 'achievement.nightmare_god'
+        | 'achievement.banishment'
+        | 'achievement.warrior'
+        | 'achievement.conqueror'
+        | 'achievement.fool'
+        | 'achievement.hollow_knight'
+        | 'achievement.sealed_siblings'
+        | 'achievement.dream_no_more'
+        | 'achievement.completion'
+        | 'achievement.speedrun_1'
+        | 'achievement.speedrun_2'
+        | 'achievement.speed_completion'
+        | 'achievement.steel_soul'
+        | 'achievement.brotherhood'
+        | 'achievement.inspiration'
+        | 'achievement.focus'
+        | 'achievement.soul_and_shade'
+        | 'achievement.embrace_the_void';
+};
+
+/** Hollow Knight checklist, as returned by `Parser.get_map`. */
+export type HollowKnightChecks = {
+    [Category in keyof HollowKnightCheckIds]: Record<
+        HollowKnightCheckIds[Category],
+        CheckState
+    >;
+};
+
+/** Silksong check labels, by planner category. */
+export type SilksongChecksKeys = {
+    bosses:
+        | '[Moss Mother]'
+        | '[Bell Beast]'
+        | '[Lace] #1'
+        | '[Fourth Chorus]'
+        | '[Savage Beastfly]'
+        | '[Sister Splinter]'
+        | '[Skull Tyrant]'
+        | '[Widow]'
+        | '[Moorwing]'
+        | '[Phantom]'
+        | '[Last Judge]'
+        | '[Cogwork Dancers]'
+        | '[Trobbio]'
+        | '[Forebrothers Signis & Gron]'
+        | '[Disgraced Chef Lugoli]'
+        | '[Father of the Flame]'
+        | '[Groal the Great]'
+        | '[Raging Conchfly]'
+        | '[Lace] #2';
+    abilities:
+        | '[Swift Step]'
+        | '[Cling Grip]'
+        | '[Needolin]'
+        | '[Clawline]'
+        | '[Needle Strike]'
+        | "[Drifter's Cloak]"
+        | '[Faydown Cloak]'
+        | '[Silk Soar]'
+        | '[Elegy of the Deep]';
+    needle:
+        | '[Sharpened Needle](Needle#Upgrades)'
+        | '[Shining Needle](Needle#Upgrades)'
+        | '[Hivesteel Needle](Needle#Upgrades)'
+        | '[Pale Steel Needle](Needle#Upgrades)';
+    silkSkills:
+        | '[Silkspear]'
+        | '[Thread Storm]'
+        | '[Cross Stitch]'
+        | '[Sharpdart]'
+        | '[Rune Rage]'
+        | '[Pale Nails]';
+    tools:
+        | '[Straight Pin]'
+        | '[Threefold Pin]'
+        | '[Sting Shard]'
+        | '[Tacks]'
+        | '[Longpin]'
+        | '[Curveclaw]'
+        | '[Curvesickle]'
+        | '[Throwing Ring]'
+        | '[Pimpillo]'
+        | '[Conchcutter]'
+        | '[Silkshot]'
+        | "[Delver's Drill]"
+        | '[Cogwork Wheel]'
+        | '[Cogfly]'
+        | '[Rosary Cannon]'
+        | '[Voltvessels]'
+        | '[Flintslate]'
+        | '[Snare Setter]'
+        | '[Flea Brew]'
+        | '[Plasmium Phial]'
+        | '[Needle Phial]'
+        | "[Druid's Eye]"
+        | "[Druid's Eyes]"
+        | '[Magma Bell]'
+        | '[Warding Bell]'
+        | '[Pollip Pouch]'
+        | '[Fractured Mask]'
+        | '[Multibinder]'
+        | '[Weavelight]'
+        | '[Sawtooth Circlet]'
+        | '[Injector Band]'
+        | '[Spool Extender]'
+        | '[Reserve Bind]'
+        | '[Claw Mirror]'
+        | '[Memory Crystal]'
+        | '[Snitch Pick]'
+        | '[Volt Filament]'
+        | '[Quick Sling]'
+        | '[Wreath of Purity]'
+        | '[Longclaw]'
+        | '[Wispfire Lantern]'
+        | '[Egg of Flealia]'
+        | '[Pin Badge]'
+        | '[Compass]'
+        | '[Shard Pendant]'
+        | '[Magnetite Brooch]'
+        | '[Weighted Belt]'
+        | '[Barbed Bracelet]'
+        | "[Dead Bug's Purse]"
+        | '[Shell Satchel]'
+        | '[Magnetite Dice]'
+        | '[Scuttlebrace]'
+        | "[Ascendant's Grip]"
+        | '[Spider Strings]'
+        | '[Silkspeed Anklets]'
+        | "[Thief's Mark]";
+    crests:
+        | '[Crest of the Hunter]'
+        | '[Crest of the Reaper]'
+        | '[Crest of the Wanderer]'
+        | '[Crest of the Beast]'
+        | '[Crest of the Witch]'
+        | '[Crest of the Architect]'
+        | '[Crest of the Shaman]';
+    toolUpgrades:
+        | '[Tool Pouch] #1'
+        | '[Tool Pouch] #2'
+        | '[Tool Pouch] #3'
+        | '[Tool Pouch] #4'
+        | '[Crafting Kit] #1'
+        | '[Crafting Kit] #2'
+        | '[Crafting Kit] #3'
+        | '[Crafting Kit] #4';
+    maskShards:
+        | '[Mask Shard] #1'
+        | '[Mask Shard] #2'
+        | '[Mask Shard] #3'
+        | '[Mask Shard] #4'
+        | '[Mask Shard] #5'
+        | '[Mask Shard] #6'
+        | '[Mask Shard] #7'
+        | '[Mask Shard] #8'
+        | '[Mask Shard] #9'
+        | '[Mask Shard] #10'
+        | '[Mask Shard] #11'
+        | '[Mask Shard] #12'
+        | '[Mask Shard] #13'
+        | '[Mask Shard] #14'
+        | '[Mask Shard] #15'
+        | '[Mask Shard] #16'
+        | '[Mask Shard] #17'
+        | '[Mask Shard] #18'
+        | '[Mask Shard] #19'
+        | '[Mask Shard] #20';
+    spoolFragments:
+        | '[Spool Fragment] #1'
+        | '[Spool Fragment] #2'
+        | '[Spool Fragment] #3'
+        | '[Spool Fragment] #4'
+        | '[Spool Fragment] #5'
+        | '[Spool Fragment] #6'
+        | '[Spool Fragment] #7'
+        | '[Spool Fragment] #8'
+        | '[Spool Fragment] #9'
+        | '[Spool Fragment] #10'
+        | '[Spool Fragment] #11'
+        | '[Spool Fragment] #12'
+        | '[Spool Fragment] #13'
+        | '[Spool Fragment] #14'
+        | '[Spool Fragment] #15'
+        | '[Spool Fragment] #16'
+        | '[Spool Fragment] #17'
+        | '[Spool Fragment] #18';
+    silkHearts: '[Silk Heart] #1' | '[Silk Heart] #2' | '[Silk Heart] #3';
+    quests: string;
+};
+
+/** Silksong check ids, by category. */
+export type SilksongCheckIds = {
+    bosses:
+        | 'boss.moss_mother'
+        | 'boss.bell_beast'
+        | 'boss.lace_1'
+        | 'boss.fourth_chorus'
+        | 'boss.savage_beastfly'
+        | 'boss.sister_splinter'
+        | 'boss.skull_tyrant'
+        | 'boss.widow'
+        | 'boss.moorwing'
+        | 'boss.phantom'
+        | 'boss.last_judge'
+        | 'boss.cogwork_dancers'
+        | 'boss.trobbio'
+        | 'boss.forebrothers_signis_and_gron'
+        | 'boss.disgraced_chef_lugoli'
+        | 'boss.father_of_the_flame'
+        | 'boss.groal_the_great'
+        | 'boss.raging_conchfly'
+        | 'boss.lace_2';
+    abilities:
+        | 'ability.swift_step'
+        | 'ability.cling_grip'
+        | 'ability.needolin'
+        | 'ability.clawline'
+        | 'ability.needle_strike'
+        | 'ability.drifters_cloak'
+        | 'ability.faydown_cloak'
+        | 'ability.silk_soar'
+        | 'ability.elegy_of_the_deep';
+    needle:
+        | 'needle.sharpened_needle'
+        | 'needle.shining_needle'
+        | 'needle.hivesteel_needle'
+        | 'needle.pale_steel_needle';
+    silkSkills:
+        | 'silk_skill.silkspear'
+        | 'silk_skill.thread_storm'
+        | 'silk_skill.cross_stitch'
+        | 'silk_skill.sharpdart'
+        | 'silk_skill.rune_rage'
+        | 'silk_skill.pale_nails';
+    tools:
+        | 'tool.straight_pin'
+        | 'tool.threefold_pin'
+        | 'tool.sting_shard'
+        | 'tool.tacks'
+        | 'tool.longpin'
+        | 'tool.curveclaw'
+        | 'tool.curvesickle'
+        | 'tool.throwing_ring'
+        | 'tool.pimpillo'
+        | 'tool.conchcutter'
+        | 'tool.silkshot'
+        | 'tool.delvers_drill'
+        | 'tool.cogwork_wheel'
+        | 'tool.cogfly'
+        | 'tool.rosary_cannon'
+        | 'tool.voltvessels'
+        | 'tool.flintslate'
+        | 'tool.snare_setter'
+        | 'tool.flea_brew'
+        | 'tool.plasmium_phial'
+        | 'tool.needle_phial'
+        | 'tool.druids_eye'
+        | 'tool.druids_eyes'
+        | 'tool.magma_bell'
+        | 'tool.warding_bell'
+        | 'tool.pollip_pouch'
+        | 'tool.fractured_mask'
+        | 'tool.multibinder'
+        | 'tool.weavelight'
+        | 'tool.sawtooth_circlet'
+        | 'tool.injector_band'
+        | 'tool.spool_extender'
+        | 'tool.reserve_bind'
+        | 'tool.claw_mirror'
+        | 'tool.memory_crystal'
+        | 'tool.snitch_pick'
+        | 'tool.volt_filament'
+        | 'tool.quick_sling'
+        | 'tool.wreath_of_purity'
+        | 'tool.longclaw'
+        | 'tool.wispfire_lantern'
+        | 'tool.egg_of_flealia'
+        | 'tool.pin_badge'
+        | 'tool.compass'
+        | 'tool.shard_pendant'
+        | 'tool.magnetite_brooch'
+        | 'tool.weighted_belt'
+        | 'tool.barbed_bracelet'
+        | 'tool.dead_bugs_purse'
+        | 'tool.shell_satchel'
+        | 'tool.magnetite_dice'
+        | 'tool.scuttlebrace'
+        | 'tool.ascendants_grip'
+        | 'tool.spider_strings'
+        | 'tool.silkspeed_anklets'
+        | 'tool.thiefs_mark';
+    crests:
+        | 'crest.hunter'
+        | 'crest.reaper'
+        | 'crest.wanderer'
+        | 'crest.beast'
+        | 'crest.witch'
+        | 'crest.architect'
+        | 'crest.shaman';
+    toolUpgrades:
+        | 'tool_upgrade.tool_pouch_1'
+        | 'tool_upgrade.tool_pouch_2'
+        | 'tool_upgrade.tool_pouch_3'
+        | 'tool_upgrade.tool_pouch_4'
+        | 'tool_upgrade.crafting_kit_1'
+        | 'tool_upgrade.crafting_kit_2'
+        | 'tool_upgrade.crafting_kit_3'
+        | 'tool_upgrade.crafting_kit_4';
+    maskShards:
+        | 'mask_shard.1'
+        | 'mask_shard.2'
+        | 'mask_shard.3'
+        | 'mask_shard.4'
+        | 'mask_shard.5'
+        | 'mask_shard.6'
+        | 'mask_shard.7'
+        | 'mask_shard.8'
+        | 'mask_shard.9'
+        | 'mask_shard.10'
+        | 'mask_shard.11'
+        | 'mask_shard.12'
+        | 'mask_shard.13'
+        | 'mask_shard.14'
+        | 'mask_shard.15'
+        | 'mask_shard.16'
+        | 'mask_shard.17'
+        | 'mask_shard.18'
+        | 'mask_shard.19'
+        | 'mask_shard.20';
+    spoolFragments:
+        | 'spool_fragment.1'
+        | 'spool_fragment.2'
+        | 'spool_fragment.3'
+        | 'spool_fragment.4'
+        | 'spool_fragment.5'
+        | 'spool_fragment.6'
+        | 'spool_fragment.7'
+        | 'spool_fragment.8'
+        | 'spool_fragment.9'
+        | 'spool_fragment.10'
+        | 'spool_fragment.11'
+        | 'spool_fragment.12'
+        | 'spool_fragment.13'
+        | 'spool_fragment.14'
+        | 'spool_fragment.15'
+        | 'spool_fragment.16'
+        | 'spool_fragment.17'
+        | 'spool_fragment.18';
+    silkHearts: 'silk_heart.1' | 'silk_heart.2' | 'silk_heart.3';
+    quests: string;
+};
+
+/** Silksong checklist, as returned by `Parser.get_map`. */
+export type SilksongChecks = {
+    [Category in keyof SilksongCheckIds]: Record<
+        SilksongCheckIds[Category],
+        CheckState
+    >;
+};
+
+/** Checklist of whichever game the save belongs to. */
+export type GameSer =
+    | { 'hollow-knight': HollowKnightChecks }
+    | { silksong: SilksongChecks };